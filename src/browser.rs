@@ -0,0 +1,271 @@
+use crate::{Data, Error, validate};
+use reqwest::header::{ACCEPT, ACCEPT_LANGUAGE, HeaderMap, USER_AGENT};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+/// Requirements: [Y, N, U]
+///
+/// Результат выполнения 3DS Method:
+///
+/// - Y — выполнен успешно;
+/// - N — выполнен с ошибкой или не завершился за 10 секунд;
+/// - U — ACS не поддерживает 3DS Method.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThreeDsCompInd {
+    /// Y — 3DS Method выполнен.
+    #[serde(rename = "Y")]
+    Completed,
+    /// N — 3DS Method не выполнен.
+    #[serde(rename = "N")]
+    NotCompleted,
+    /// U — 3DS Method недоступен.
+    #[serde(rename = "U")]
+    Unavailable,
+}
+
+impl ThreeDsCompInd {
+    /// RU: Значение в том виде, в котором его передает API.
+    /// EN: Value as transmitted by the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Completed => "Y",
+            Self::NotCompleted => "N",
+            Self::Unavailable => "U",
+        }
+    }
+}
+
+/// Параметры браузера, которые собираются на стороне клиента скриптом.
+///
+/// Поля совпадают с названиями свойств в JavaScript, поэтому структуру можно принять
+/// от фронтенда как есть:
+///
+/// ```js
+/// { language: navigator.language, timezoneOffset: new Date().getTimezoneOffset(),
+///   screenHeight: screen.height, screenWidth: screen.width,
+///   colorDepth: screen.colorDepth, javaEnabled: false }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct BrowserInfo {
+    /// Язык браузера, например `ru-RU`. Если пустой — берется из `Accept-Language`.
+    #[serde(default)]
+    pub language: String,
+    /// Смещение часового пояса от UTC в минутах, как возвращает `getTimezoneOffset()`.
+    pub timezone_offset: i32,
+    /// Высота экрана в пикселях.
+    pub screen_height: u32,
+    /// Ширина экрана в пикселях.
+    pub screen_width: u32,
+    /// Глубина цвета в битах.
+    pub color_depth: u8,
+    /// Включена ли Java в браузере.
+    #[serde(default)]
+    pub java_enabled: bool,
+}
+
+/// Данные браузера покупателя для 3-D Secure v2.
+///
+/// Передаются в `DATA` запроса `FinishAuthorize`, см.
+/// [FinishAuthorizeReq::browser_data](crate::FinishAuthorizeReq::browser_data).
+///
+/// [Подробнее](https://developer.tbank.ru/eacq/intro/developer/3ds)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BrowserData {
+    three_ds_comp_ind: ThreeDsCompInd,
+    language: String,
+    timezone: i32,
+    screen_height: u32,
+    screen_width: u32,
+    color_depth: u8,
+    java_enabled: bool,
+    accept_header: String,
+    user_agent: String,
+    cres_callback_url: Url,
+}
+
+impl BrowserData {
+    /// RU: Данные браузера из параметров, собранных скриптом, и заголовков запроса.
+    /// EN: Browser data from the JS-collected info and explicit header values.
+    pub fn new(
+        info: BrowserInfo,
+        accept_header: impl Into<String>,
+        user_agent: impl Into<String>,
+        three_ds_comp_ind: ThreeDsCompInd,
+        cres_callback_url: Url,
+    ) -> Result<Self, Error> {
+        let data = Self {
+            three_ds_comp_ind,
+            language: info.language,
+            timezone: info.timezone_offset,
+            screen_height: info.screen_height,
+            screen_width: info.screen_width,
+            color_depth: info.color_depth,
+            java_enabled: info.java_enabled,
+            accept_header: accept_header.into(),
+            user_agent: user_agent.into(),
+            cres_callback_url,
+        };
+        data.validate()?;
+
+        Ok(data)
+    }
+
+    /// RU: Данные браузера из заголовков HTTP-запроса покупателя и параметров, собранных
+    /// скриптом. `Accept` и `User-Agent` берутся из заголовков; если скрипт не передал язык,
+    /// он берется из `Accept-Language`.
+    /// EN: Browser data from the customer's HTTP request headers plus the JS-collected info.
+    pub fn from_headers(
+        headers: &HeaderMap,
+        mut info: BrowserInfo,
+        three_ds_comp_ind: ThreeDsCompInd,
+        cres_callback_url: Url,
+    ) -> Result<Self, Error> {
+        let header = |name| {
+            headers
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .unwrap_or_default()
+        };
+
+        if info.language.is_empty() {
+            info.language = header(ACCEPT_LANGUAGE)
+                .split([',', ';'])
+                .next()
+                .unwrap_or_default()
+                .trim()
+                .to_string();
+        }
+
+        Self::new(
+            info,
+            header(ACCEPT),
+            header(USER_AGENT),
+            three_ds_comp_ind,
+            cres_callback_url,
+        )
+    }
+
+    /// RU: Параметры для `DATA`. EN: Parameters to put into `DATA`.
+    pub fn to_data(&self) -> Data {
+        [
+            (
+                "threeDSCompInd",
+                self.three_ds_comp_ind.as_str().to_string(),
+            ),
+            ("language", self.language.clone()),
+            ("timezone", self.timezone.to_string()),
+            ("screen_height", self.screen_height.to_string()),
+            ("screen_width", self.screen_width.to_string()),
+            ("colorDepth", self.color_depth.to_string()),
+            ("javaEnabled", self.java_enabled.to_string()),
+            ("acceptHeader", self.accept_header.clone()),
+            ("userAgent", self.user_agent.clone()),
+            ("cresCallbackUrl", self.cres_callback_url.to_string()),
+        ]
+        .into_iter()
+        .fold(Data::new(), |data, (key, value)| data.insert(key, value))
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        validate::length("language", &self.language, 1, 8)?;
+        validate::length("timezone", &self.timezone.to_string(), 1, 5)?;
+        validate::length("screen_height", &self.screen_height.to_string(), 1, 6)?;
+        validate::length("screen_width", &self.screen_width.to_string(), 1, 6)?;
+        validate::length("acceptHeader", &self.accept_header, 1, 2048)?;
+        validate::length("userAgent", &self.user_agent, 1, 2048)?;
+        validate::length("cresCallbackUrl", self.cres_callback_url.as_str(), 1, 256)?;
+
+        if ![1, 4, 8, 15, 16, 24, 32, 48].contains(&self.color_depth) {
+            return Err(Error::Validation {
+                field: "colorDepth",
+                reason: format!("unsupported color depth {}", self.color_depth),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    fn info() -> BrowserInfo {
+        serde_json::from_str(
+            r#"{"timezoneOffset":-180,"screenHeight":1080,"screenWidth":1920,"colorDepth":24}"#,
+        )
+        .unwrap()
+    }
+
+    fn callback() -> Url {
+        Url::parse("https://shop.example/3ds/cres").unwrap()
+    }
+
+    #[test]
+    fn builds_from_headers() {
+        let mut headers = HeaderMap::new();
+        let _ = headers.insert(ACCEPT, HeaderValue::from_static("text/html"));
+        let _ = headers.insert(USER_AGENT, HeaderValue::from_static("Mozilla/5.0"));
+        let _ = headers.insert(
+            ACCEPT_LANGUAGE,
+            HeaderValue::from_static("ru-RU,ru;q=0.9,en;q=0.8"),
+        );
+
+        let data =
+            BrowserData::from_headers(&headers, info(), ThreeDsCompInd::Completed, callback())
+                .unwrap()
+                .to_data();
+        let data = serde_json::to_value(&data).unwrap();
+
+        assert_eq!(data["threeDSCompInd"], "Y");
+        assert_eq!(data["language"], "ru-RU");
+        assert_eq!(data["timezone"], "-180");
+        assert_eq!(data["screen_width"], "1920");
+        assert_eq!(data["colorDepth"], "24");
+        assert_eq!(data["javaEnabled"], "false");
+        assert_eq!(data["acceptHeader"], "text/html");
+        assert_eq!(data["userAgent"], "Mozilla/5.0");
+        assert_eq!(data["cresCallbackUrl"], "https://shop.example/3ds/cres");
+    }
+
+    #[test]
+    fn rejects_invalid_sizes() {
+        let missing_headers = BrowserData::from_headers(
+            &HeaderMap::new(),
+            info(),
+            ThreeDsCompInd::Unavailable,
+            callback(),
+        );
+        assert!(matches!(
+            missing_headers,
+            Err(Error::Validation {
+                field: "language",
+                ..
+            })
+        ));
+
+        let mut wide = info();
+        wide.language = "ru".to_string();
+        wide.screen_width = 10_000_000;
+        assert!(matches!(
+            BrowserData::new(wide, "*/*", "UA", ThreeDsCompInd::Completed, callback()),
+            Err(Error::Validation {
+                field: "screen_width",
+                ..
+            })
+        ));
+
+        let mut odd = info();
+        odd.language = "ru".to_string();
+        odd.color_depth = 30;
+        assert!(matches!(
+            BrowserData::new(odd, "*/*", "UA", ThreeDsCompInd::Completed, callback()),
+            Err(Error::Validation {
+                field: "colorDepth",
+                ..
+            })
+        ));
+    }
+}
@@ -0,0 +1,722 @@
+use crate::{
+    CardId, Client, CustomerKey, Data, Description, EncryptedCardData, Error, PaymentId, RebillId,
+    TerminalKey,
+};
+use chrono::{DateTime, Datelike, Utc};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::net::IpAddr;
+use url::Url;
+
+/// Сохраненная карта покупателя.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct Card {
+    /// Идентификатор карты в системе Т‑Бизнес.
+    pub card_id: CardId,
+    /// Замаскированный номер карты, например `430000******0777`.
+    pub pan: String,
+    /// Срок действия карты.
+    pub exp_date: Option<ExpDate>,
+    /// Тип карты.
+    pub card_type: Option<CardType>,
+    /// Статус карты.
+    pub status: CardStatus,
+    /// Идентификатор автоплатежа, если карта сохранена для рекуррентных платежей.
+    pub rebill_id: Option<RebillId>,
+}
+
+impl Card {
+    /// RU: Карту можно показать покупателю для оплаты на момент `now`: активна, не истекла и
+    /// подходит для списания.
+    /// EN: Whether the card can be offered for payment at `now`: active, not expired and
+    /// usable for charges.
+    pub fn is_usable_at(&self, now: DateTime<Utc>) -> bool {
+        self.status == CardStatus::Active
+            && self.exp_date.is_none_or(|exp| !exp.is_expired_at(now))
+            && self
+                .card_type
+                .is_none_or(|t| matches!(t, CardType::Payment | CardType::PaymentAndPayout))
+    }
+}
+
+/// Список сохраненных карт покупателя.
+#[derive(Deserialize, Debug)]
+#[serde(transparent)]
+pub struct CardList(pub Vec<Card>);
+
+impl CardList {
+    /// RU: Карты, которые можно показать покупателю для оплаты, см. [Card::is_usable_at].
+    /// EN: Cards that can be offered for payment, see [Card::is_usable_at].
+    pub fn usable(&self) -> impl Iterator<Item = &Card> {
+        let now = Utc::now();
+        self.0.iter().filter(move |card| card.is_usable_at(now))
+    }
+}
+
+/// Срок действия карты, в API передается в формате MMYY.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExpDate {
+    month: u32,
+    year: i32,
+}
+
+impl ExpDate {
+    /// RU: Месяц окончания срока действия. EN: Expiry month.
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    /// RU: Год окончания срока действия. EN: Expiry year.
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// RU: Карта действует до конца месяца — истекла, если этот месяц уже прошел.
+    /// EN: Cards are valid through the end of the month.
+    pub fn is_expired_at(&self, now: DateTime<Utc>) -> bool {
+        (self.year, self.month) < (now.year(), now.month())
+    }
+}
+
+impl std::str::FromStr for ExpDate {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        let invalid = || Error::Deserialize {
+            message: "expected MMYY".to_string(),
+            path: "ExpDate".to_string(),
+            raw: s.to_string(),
+        };
+
+        if s.len() != 4 || !s.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(invalid());
+        }
+        let month: u32 = s[..2].parse().map_err(|_| invalid())?;
+        let year: i32 = s[2..].parse().map_err(|_| invalid())?;
+        if !(1..=12).contains(&month) {
+            return Err(invalid());
+        }
+
+        Ok(Self {
+            month,
+            year: 2000 + year,
+        })
+    }
+}
+
+impl fmt::Display for ExpDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}{:02}", self.month, self.year.rem_euclid(100))
+    }
+}
+
+impl<'de> Deserialize<'de> for ExpDate {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// Requirements: [0, 1, 2]
+///
+/// Тип карты:
+///
+/// - 0 — карта списания;
+/// - 1 — карта пополнения;
+/// - 2 — карта пополнения и списания.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CardType {
+    /// 0 — карта списания.
+    Payment,
+    /// 1 — карта пополнения.
+    Payout,
+    /// 2 — карта пополнения и списания.
+    PaymentAndPayout,
+    /// Тип, неизвестный SDK.
+    Unknown,
+}
+
+impl<'de> Deserialize<'de> for CardType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match crate::payment::string_or_number(deserializer)?.as_str() {
+            "0" => Ok(Self::Payment),
+            "1" => Ok(Self::Payout),
+            "2" => Ok(Self::PaymentAndPayout),
+            _ => Ok(Self::Unknown),
+        }
+    }
+}
+
+/// Requirements: [A, I, E, D]
+///
+/// Статус карты:
+///
+/// - A — активная;
+/// - I — неактивная;
+/// - E — истек срок действия;
+/// - D — удалена.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CardStatus {
+    /// A — активная.
+    Active,
+    /// I — неактивная.
+    Inactive,
+    /// E — истек срок действия.
+    Expired,
+    /// D — удалена.
+    Deleted,
+    /// Статус, неизвестный SDK.
+    Unknown(String),
+}
+
+impl CardStatus {
+    /// RU: Статус в том виде, в котором его передает API.
+    /// EN: Status as transmitted by the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Active => "A",
+            Self::Inactive => "I",
+            Self::Expired => "E",
+            Self::Deleted => "D",
+            Self::Unknown(s) => s,
+        }
+    }
+}
+
+impl From<&str> for CardStatus {
+    fn from(s: &str) -> Self {
+        match s {
+            "A" => Self::Active,
+            "I" => Self::Inactive,
+            "E" => Self::Expired,
+            "D" => Self::Deleted,
+            other => Self::Unknown(other.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for CardStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for CardStatus {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for CardStatus {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Ok(Self::from(s.as_str()))
+    }
+}
+
+/// Запрос списка карт покупателя.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+struct GetCardListReq<'a> {
+    customer_key: &'a CustomerKey,
+}
+
+/// Запрос на удаление карты.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+struct RemoveCardReq<'a> {
+    customer_key: &'a CustomerKey,
+    card_id: &'a CardId,
+}
+
+/// Ответ на удаление карты.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct RemoveCardRes {
+    /// Идентификатор терминала.
+    pub terminal_key: TerminalKey,
+    /// Идентификатор покупателя в системе мерчанта.
+    pub customer_key: CustomerKey,
+    /// Идентификатор карты в системе Т‑Бизнес.
+    pub card_id: CardId,
+    /// Статус карты: D — удалена.
+    pub status: Option<CardStatus>,
+    /// Тип карты.
+    pub card_type: Option<CardType>,
+    /// Успешность прохождения запроса.
+    pub success: bool,
+    /// Код ошибки. `0` — если ошибки не произошло.
+    pub error_code: String,
+    /// Краткое описание ошибки.
+    pub message: Option<String>,
+    /// Подробное описание ошибки.
+    pub details: Option<String>,
+}
+
+/// Идентификатор запроса на привязку карты.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct RequestKey(String);
+
+impl RequestKey {
+    /// RU: Идентификатор запроса из строки. EN: Request key from a string.
+    pub fn new(key: impl Into<String>) -> Self {
+        Self(key.into())
+    }
+
+    /// RU: Идентификатор в виде строки. EN: Request key as a string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Requirements: [NO, HOLD, 3DS, 3DSHOLD]
+///
+/// Тип проверки при привязке карты:
+///
+/// - NO — сохранить карту без проверок. RebillId для рекуррентных платежей не возвращается;
+/// - HOLD — при сохранении сделать списание и затем отмену на 1 руб. RebillId возвращается;
+/// - 3DS — при сохранении карты выполнить проверку 3DS, списание и отмену на 1 руб. RebillId возвращается;
+/// - 3DSHOLD — проверка 3DS выполняется, только если карта вовлечена в 3DS; списание и отмена на 1 руб.
+///
+/// Если параметр не передан, используется значение NO.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CheckType {
+    /// NO — без проверок.
+    #[default]
+    #[serde(rename = "NO")]
+    No,
+    /// HOLD — списание и отмена 1 руб.
+    #[serde(rename = "HOLD")]
+    Hold,
+    /// 3DS — проверка 3DS, списание и отмена 1 руб.
+    #[serde(rename = "3DS")]
+    ThreeDs,
+    /// 3DSHOLD — проверка 3DS, если карта в нее вовлечена, списание и отмена 1 руб.
+    #[serde(rename = "3DSHOLD")]
+    ThreeDsHold,
+}
+
+/// Статус привязки карты.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum BindingStatus {
+    /// NEW — привязка создана.
+    New,
+    /// FORM_SHOWED — покупатель открыл форму привязки.
+    FormShowed,
+    /// 3DS_CHECKING — покупатель проходит проверку 3-D Secure.
+    ThreeDsChecking,
+    /// 3DS_CHECKED — покупатель прошел проверку 3-D Secure.
+    ThreeDsChecked,
+    /// AUTHORIZING — проверочное списание обрабатывается.
+    Authorizing,
+    /// AUTHORIZED — проверочное списание прошло.
+    Authorized,
+    /// COMPLETED — карта привязана.
+    Completed,
+    /// REJECTED — привязка отклонена.
+    Rejected,
+    /// Статус, неизвестный SDK.
+    Unknown(String),
+}
+
+impl BindingStatus {
+    /// RU: Статус в том виде, в котором его передает API.
+    /// EN: Status as transmitted by the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::New => "NEW",
+            Self::FormShowed => "FORM_SHOWED",
+            Self::ThreeDsChecking => "3DS_CHECKING",
+            Self::ThreeDsChecked => "3DS_CHECKED",
+            Self::Authorizing => "AUTHORIZING",
+            Self::Authorized => "AUTHORIZED",
+            Self::Completed => "COMPLETED",
+            Self::Rejected => "REJECTED",
+            Self::Unknown(s) => s,
+        }
+    }
+
+    /// RU: Привязка завершена — успешно или нет.
+    /// EN: The binding has finished, successfully or not.
+    pub fn is_final(&self) -> bool {
+        matches!(self, Self::Completed | Self::Rejected)
+    }
+}
+
+impl From<&str> for BindingStatus {
+    fn from(s: &str) -> Self {
+        match s {
+            "NEW" => Self::New,
+            "FORM_SHOWED" => Self::FormShowed,
+            "3DS_CHECKING" => Self::ThreeDsChecking,
+            "3DS_CHECKED" => Self::ThreeDsChecked,
+            "AUTHORIZING" => Self::Authorizing,
+            "AUTHORIZED" => Self::Authorized,
+            "COMPLETED" => Self::Completed,
+            "REJECTED" => Self::Rejected,
+            other => Self::Unknown(other.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for BindingStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for BindingStatus {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for BindingStatus {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Ok(Self::from(s.as_str()))
+    }
+}
+
+/// Запрос на привязку карты без оплаты.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct AddCardReq {
+    customer_key: CustomerKey,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    check_type: Option<CheckType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<Description>,
+    #[serde(rename = "IP", skip_serializing_if = "Option::is_none")]
+    ip: Option<IpAddr>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    resident_state: Option<bool>,
+}
+
+impl AddCardReq {
+    /// RU: Привязать карту к покупателю. EN: Bind a card to the customer.
+    pub fn new(customer_key: CustomerKey) -> Self {
+        Self {
+            customer_key,
+            check_type: None,
+            description: None,
+            ip: None,
+            resident_state: None,
+        }
+    }
+
+    /// RU: Тип проверки карты. EN: Card check type.
+    pub fn check_type(mut self, check_type: CheckType) -> Self {
+        self.check_type = Some(check_type);
+        self
+    }
+
+    /// RU: Описание, которое будет показано на форме. EN: Description shown on the form.
+    pub fn description(mut self, description: Description) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    /// RU: IP-адрес покупателя. EN: Customer IP address.
+    pub fn ip(mut self, ip: IpAddr) -> Self {
+        self.ip = Some(ip);
+        self
+    }
+
+    /// RU: Признак резидентства РФ. EN: Whether the customer is a Russian resident.
+    pub fn resident_state(mut self, resident: bool) -> Self {
+        self.resident_state = Some(resident);
+        self
+    }
+}
+
+/// Ответ на запрос привязки карты.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct AddCardRes {
+    /// Идентификатор терминала.
+    pub terminal_key: TerminalKey,
+    /// Идентификатор покупателя в системе мерчанта.
+    pub customer_key: CustomerKey,
+    /// Идентификатор запроса на привязку карты.
+    pub request_key: RequestKey,
+    /// Идентификатор платежа, если привязка проходит со списанием.
+    pub payment_id: Option<PaymentId>,
+    /// Ссылка на форму привязки карты.
+    #[serde(rename = "PaymentURL")]
+    pub payment_url: Option<Url>,
+    /// Успешность прохождения запроса.
+    pub success: bool,
+    /// Код ошибки. `0` — если ошибки не произошло.
+    pub error_code: String,
+    /// Краткое описание ошибки.
+    pub message: Option<String>,
+    /// Подробное описание ошибки.
+    pub details: Option<String>,
+}
+
+/// Запрос на привязку карты с формы мерчанта.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct AttachCardReq {
+    request_key: RequestKey,
+    card_data: EncryptedCardData,
+    #[serde(rename = "DATA", skip_serializing_if = "Option::is_none")]
+    data: Option<Data>,
+}
+
+impl AttachCardReq {
+    /// RU: Привязать карту по запросу `request_key`, см. [CardData::encrypt](crate::CardData::encrypt).
+    /// EN: Attach a card for `request_key` using encrypted card data.
+    pub fn new(request_key: RequestKey, card_data: EncryptedCardData) -> Self {
+        Self {
+            request_key,
+            card_data,
+            data: None,
+        }
+    }
+
+    /// RU: Дополнительные параметры, например данные браузера для 3DS.
+    /// EN: Additional parameters, e.g. browser data for 3DS.
+    pub fn data(mut self, data: Data) -> Self {
+        self.data = Some(data);
+        self
+    }
+}
+
+/// Ответ на привязку карты с формы мерчанта.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct AttachCardRes {
+    /// Идентификатор терминала.
+    pub terminal_key: TerminalKey,
+    /// Идентификатор покупателя в системе мерчанта.
+    pub customer_key: Option<CustomerKey>,
+    /// Идентификатор запроса на привязку карты.
+    pub request_key: RequestKey,
+    /// Идентификатор привязанной карты.
+    pub card_id: Option<CardId>,
+    /// Идентификатор автоплатежа.
+    pub rebill_id: Option<RebillId>,
+    /// Статус привязки.
+    pub status: Option<BindingStatus>,
+    /// Успешность прохождения запроса.
+    pub success: bool,
+    /// Код ошибки. `0` — если ошибки не произошло.
+    pub error_code: String,
+    /// Краткое описание ошибки.
+    pub message: Option<String>,
+    /// Подробное описание ошибки.
+    pub details: Option<String>,
+    /// Адрес сервера ACS для проверки 3-D Secure.
+    #[serde(rename = "ACSUrl")]
+    pub acs_url: Option<Url>,
+    /// Уникальный идентификатор транзакции 3-D Secure v1.
+    #[serde(rename = "MD")]
+    pub md: Option<String>,
+    /// Данные запроса 3-D Secure v1.
+    pub pa_req: Option<String>,
+}
+
+/// Запрос статуса привязки карты.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+struct GetAddCardStateReq<'a> {
+    request_key: &'a RequestKey,
+}
+
+/// Статус привязки карты.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct GetAddCardStateRes {
+    /// Идентификатор терминала.
+    pub terminal_key: TerminalKey,
+    /// Идентификатор запроса на привязку карты.
+    pub request_key: RequestKey,
+    /// Статус привязки.
+    pub status: BindingStatus,
+    /// Успешность прохождения запроса.
+    pub success: bool,
+    /// Код ошибки. `0` — если ошибки не произошло.
+    pub error_code: String,
+    /// Идентификатор покупателя в системе мерчанта.
+    pub customer_key: Option<CustomerKey>,
+    /// Идентификатор привязанной карты.
+    pub card_id: Option<CardId>,
+    /// Идентификатор автоплатежа.
+    pub rebill_id: Option<RebillId>,
+    /// Краткое описание ошибки.
+    pub message: Option<String>,
+    /// Подробное описание ошибки.
+    pub details: Option<String>,
+}
+
+impl Client {
+    /// RU: Получить список сохраненных карт покупателя (`/v2/GetCardList`).  
+    /// EN: List the customer's saved cards (`/v2/GetCardList`).
+    pub async fn get_card_list(&self, customer_key: &CustomerKey) -> Result<CardList, Error> {
+        self.post("GetCardList", &GetCardListReq { customer_key })
+            .await
+    }
+
+    /// RU: Удалить сохраненную карту покупателя (`/v2/RemoveCard`).  
+    /// EN: Remove a saved card (`/v2/RemoveCard`).
+    pub async fn remove_card(
+        &self,
+        customer_key: &CustomerKey,
+        card_id: &CardId,
+    ) -> Result<RemoveCardRes, Error> {
+        self.post(
+            "RemoveCard",
+            &RemoveCardReq {
+                customer_key,
+                card_id,
+            },
+        )
+        .await
+    }
+
+    /// RU: Инициировать привязку карты к покупателю (`/v2/AddCard`).  
+    /// EN: Start binding a card to a customer (`/v2/AddCard`).
+    pub async fn add_card(&self, req: &AddCardReq) -> Result<AddCardRes, Error> {
+        self.post("AddCard", req).await
+    }
+
+    /// RU: Завершить привязку карты с формы мерчанта (`/v2/AttachCard`).  
+    /// EN: Finish binding a card collected on the merchant form (`/v2/AttachCard`).
+    pub async fn attach_card(&self, req: &AttachCardReq) -> Result<AttachCardRes, Error> {
+        self.post("AttachCard", req).await
+    }
+
+    /// RU: Получить статус привязки карты (`/v2/GetAddCardState`).  
+    /// EN: Get the card binding status (`/v2/GetAddCardState`).
+    pub async fn get_add_card_state(
+        &self,
+        request_key: &RequestKey,
+    ) -> Result<GetAddCardStateRes, Error> {
+        self.post("GetAddCardState", &GetAddCardStateReq { request_key })
+            .await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn parse_card_list() {
+        let json = r#"[{"Pan":"430000******0777","CardId":"0","Status":"A","RebillId":"145919","CardType":0,"ExpDate":"1122"},{"Pan":"518223******0036","CardId":"1","Status":"I","CardType":1,"ExpDate":"0130"}]"#;
+        let cards: CardList = serde_json::from_str(json).unwrap();
+
+        let first = &cards.0[0];
+        assert_eq!(first.status, CardStatus::Active);
+        assert_eq!(first.card_type, Some(CardType::Payment));
+        assert_eq!(
+            first.exp_date.map(|e| (e.month(), e.year())),
+            Some((11, 2022))
+        );
+        assert_eq!(
+            first.rebill_id.as_ref().map(RebillId::as_str),
+            Some("145919")
+        );
+    }
+
+    #[test]
+    fn keeps_unknown_card_status() {
+        let json = r#"[{"Pan":"430000******0777","CardId":"0","Status":"B","CardType":0,"ExpDate":"1230"}]"#;
+        let cards: CardList = serde_json::from_str(json).unwrap();
+        let now = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+
+        let card = &cards.0[0];
+        assert_eq!(card.status, CardStatus::Unknown("B".to_string()));
+        assert_eq!(card.status.as_str(), "B");
+        assert!(!card.is_usable_at(now));
+        assert_eq!(serde_json::to_value(&card.status).unwrap(), "B");
+    }
+
+    #[test]
+    fn filters_unusable_cards() {
+        let json = r#"[
+            {"Pan":"430000******0001","CardId":"1","Status":"A","CardType":0,"ExpDate":"1226"},
+            {"Pan":"430000******0002","CardId":"2","Status":"A","CardType":2,"ExpDate":"1126"},
+            {"Pan":"430000******0003","CardId":"3","Status":"I","CardType":0,"ExpDate":"1230"},
+            {"Pan":"430000******0004","CardId":"4","Status":"A","CardType":1,"ExpDate":"1230"}
+        ]"#;
+        let cards: CardList = serde_json::from_str(json).unwrap();
+        let now = Utc.with_ymd_and_hms(2026, 12, 31, 23, 0, 0).unwrap();
+
+        let usable: Vec<&str> = cards
+            .0
+            .iter()
+            .filter(|card| card.is_usable_at(now))
+            .map(|card| card.card_id.as_str())
+            .collect();
+
+        assert_eq!(usable, ["1"]);
+    }
+
+    #[test]
+    fn rejects_bad_exp_date() {
+        assert!("1322".parse::<ExpDate>().is_err());
+        assert!("12/2".parse::<ExpDate>().is_err());
+        assert!("0125".parse::<ExpDate>().is_ok());
+    }
+
+    #[test]
+    fn serialize_add_card() {
+        let req = AddCardReq::new(CustomerKey::new("testCustomer1234").unwrap())
+            .check_type(CheckType::ThreeDsHold);
+
+        assert_eq!(
+            serde_json::to_value(&req).unwrap(),
+            serde_json::json!({"CustomerKey": "testCustomer1234", "CheckType": "3DSHOLD"})
+        );
+    }
+
+    #[test]
+    fn parse_add_card_response() {
+        let json = r#"{"PaymentId":"6155312072","TerminalKey":"TBankTest","CustomerKey":"906540","RequestKey":"ed989549-d3be-4758-95c7-22647e03f9ec","ErrorCode":"0","Success":true,"PaymentURL":"https://securepay.tinkoff.ru/e9Bm5WgU"}"#;
+        let res: AddCardRes = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            res.payment_url.as_ref().map(Url::as_str),
+            Some("https://securepay.tinkoff.ru/e9Bm5WgU")
+        );
+        assert_eq!(
+            res.request_key.as_str(),
+            "ed989549-d3be-4758-95c7-22647e03f9ec"
+        );
+    }
+
+    #[test]
+    fn parse_add_card_state() {
+        let json = r#"{"TerminalKey":"TBankTest","RequestKey":"13021e10-a3ed-4f14-bcd1-823b5ac37390","Status":"COMPLETED","Success":true,"ErrorCode":"0","CustomerKey":"testCustomer1234","CardId":"156516516","RebillId":"145919"}"#;
+        let res: GetAddCardStateRes = serde_json::from_str(json).unwrap();
+
+        assert_eq!(res.status, BindingStatus::Completed);
+        assert!(res.status.is_final());
+        assert_eq!(
+            BindingStatus::from("HOLD"),
+            BindingStatus::Unknown("HOLD".to_string())
+        );
+    }
+
+    #[test]
+    fn unknown_card_type() {
+        let json = r#"[{"Pan":"430000******0777","CardId":"0","Status":"A","CardType":7,"ExpDate":"1130"}]"#;
+        let cards: CardList = serde_json::from_str(json).unwrap();
+
+        assert_eq!(cards.0[0].card_type, Some(CardType::Unknown));
+        assert!(!cards.0[0].is_usable_at(Utc::now()));
+    }
+
+    #[test]
+    fn parse_remove_card_response() {
+        let json = r#"{"TerminalKey":"TBankTest","Status":"D","CustomerKey":"testCustomer1234","CardId":"156516516","CardType":0,"Success":true,"ErrorCode":"0"}"#;
+        let res: RemoveCardRes = serde_json::from_str(json).unwrap();
+
+        assert_eq!(res.status, Some(CardStatus::Deleted));
+    }
+}
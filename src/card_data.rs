@@ -0,0 +1,209 @@
+use crate::{Error, ExpDate};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use rsa::pkcs8::DecodePublicKey;
+use rsa::rand_core::{CryptoRngCore, OsRng};
+use rsa::{Pkcs1v15Encrypt, RsaPublicKey};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Данные карты для оплаты или привязки с собственной платежной формы мерчанта.
+///
+/// Передавать данные карты напрямую могут только мерчанты с сертификатом PCI DSS. Перед
+/// отправкой данные сериализуются в строку `PAN=...;ExpDate=MMYY;CardHolder=...;CVV=...` и
+/// шифруются открытым ключом терминала, см. [CardData::encrypt].
+///
+/// [Подробнее](https://developer.tbank.ru/eacq/api/finish-authorize)
+#[derive(Clone)]
+pub struct CardData {
+    pan: String,
+    exp_date: ExpDate,
+    card_holder: Option<String>,
+    cvv: Option<String>,
+}
+
+impl CardData {
+    /// RU: Данные карты с номером `pan` и сроком действия `exp_date`.
+    /// EN: Card data with the card number and expiry date.
+    pub fn new(pan: impl Into<String>, exp_date: ExpDate) -> Self {
+        Self {
+            pan: pan.into(),
+            exp_date,
+            card_holder: None,
+            cvv: None,
+        }
+    }
+
+    /// RU: Имя держателя карты латиницей. EN: Cardholder name in Latin letters.
+    pub fn card_holder(mut self, card_holder: impl Into<String>) -> Self {
+        self.card_holder = Some(card_holder.into());
+        self
+    }
+
+    /// RU: Код CVV/CVC. EN: Card security code.
+    pub fn cvv(mut self, cvv: impl Into<String>) -> Self {
+        self.cvv = Some(cvv.into());
+        self
+    }
+
+    /// RU: Строка `key=value;` в том виде, в котором она шифруется.
+    /// EN: The `key=value;` string that gets encrypted.
+    ///
+    /// Содержит номер карты и CVV в открытом виде — не логируйте ее.
+    pub fn to_key_value(&self) -> String {
+        let mut pairs = vec![
+            format!("PAN={}", self.pan),
+            format!("ExpDate={}", self.exp_date),
+        ];
+        if let Some(card_holder) = &self.card_holder {
+            pairs.push(format!("CardHolder={card_holder}"));
+        }
+        if let Some(cvv) = &self.cvv {
+            pairs.push(format!("CVV={cvv}"));
+        }
+        pairs.join(";")
+    }
+
+    /// RU: Зашифровать данные карты открытым ключом терминала.
+    /// EN: Encrypt the card data with the terminal public key.
+    pub fn encrypt(&self, key: &TerminalPublicKey) -> Result<EncryptedCardData, Error> {
+        self.encrypt_with(key, &mut OsRng)
+    }
+
+    /// RU: Зашифровать данные карты с заданным источником случайности.
+    /// EN: Encrypt the card data using the given random number generator.
+    pub fn encrypt_with<R: CryptoRngCore>(
+        &self,
+        key: &TerminalPublicKey,
+        rng: &mut R,
+    ) -> Result<EncryptedCardData, Error> {
+        let encrypted = key
+            .0
+            .encrypt(rng, Pkcs1v15Encrypt, self.to_key_value().as_bytes())
+            .map_err(|e| Error::Config(format!("failed to encrypt card data: {e}")))?;
+
+        Ok(EncryptedCardData(STANDARD.encode(encrypted)))
+    }
+}
+
+impl fmt::Debug for CardData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let last4 = self
+            .pan
+            .get(self.pan.len().saturating_sub(4)..)
+            .unwrap_or("");
+
+        f.debug_struct("CardData")
+            .field("pan", &format_args!("****{last4}"))
+            .field("exp_date", &self.exp_date)
+            .field("card_holder", &self.card_holder)
+            .field("cvv", &self.cvv.as_ref().map(|_| "***"))
+            .finish()
+    }
+}
+
+/// Открытый RSA-ключ терминала для шифрования [CardData].
+///
+/// Выдается Т‑Бизнес вместе с настройками терминала.
+#[derive(Debug, Clone)]
+pub struct TerminalPublicKey(RsaPublicKey);
+
+impl TerminalPublicKey {
+    /// RU: Ключ в формате PEM или строкой base64 (X.509 SubjectPublicKeyInfo, DER).
+    /// EN: Key in PEM or as a base64 X.509 SubjectPublicKeyInfo DER string.
+    pub fn new(key: &str) -> Result<Self, Error> {
+        let key = key.trim();
+        let parsed = if key.starts_with("-----BEGIN") {
+            RsaPublicKey::from_public_key_pem(key)
+        } else {
+            let der = STANDARD
+                .decode(key)
+                .map_err(|e| Error::Config(format!("invalid terminal public key: {e}")))?;
+            RsaPublicKey::from_public_key_der(&der)
+        };
+
+        parsed
+            .map(Self)
+            .map_err(|e| Error::Config(format!("invalid terminal public key: {e}")))
+    }
+}
+
+impl From<RsaPublicKey> for TerminalPublicKey {
+    fn from(key: RsaPublicKey) -> Self {
+        Self(key)
+    }
+}
+
+/// Данные карты, зашифрованные открытым ключом терминала и закодированные в base64.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(transparent)]
+pub struct EncryptedCardData(String);
+
+impl EncryptedCardData {
+    /// RU: Зашифрованные данные в виде строки. EN: Encrypted data as a base64 string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rsa::RsaPrivateKey;
+    use rsa::pkcs8::{EncodePublicKey, LineEnding};
+
+    fn card() -> CardData {
+        CardData::new("4300000000000777", "0530".parse().unwrap())
+            .card_holder("IVAN PETROV")
+            .cvv("111")
+    }
+
+    #[test]
+    fn serializes_key_value_pairs() {
+        assert_eq!(
+            card().to_key_value(),
+            "PAN=4300000000000777;ExpDate=0530;CardHolder=IVAN PETROV;CVV=111"
+        );
+        assert_eq!(
+            CardData::new("4300000000000777", "0530".parse().unwrap()).to_key_value(),
+            "PAN=4300000000000777;ExpDate=0530"
+        );
+    }
+
+    #[test]
+    fn debug_hides_card_number() {
+        let debug = format!("{:?}", card());
+
+        assert!(!debug.contains("4300000000000777"));
+        assert!(!debug.contains("111"));
+        assert!(debug.contains("****0777"));
+    }
+
+    #[test]
+    fn encrypts_with_terminal_key() {
+        let private = RsaPrivateKey::new(&mut OsRng, 1024).unwrap();
+        let pem = private
+            .to_public_key()
+            .to_public_key_pem(LineEnding::LF)
+            .unwrap();
+        let key = TerminalPublicKey::new(&pem).unwrap();
+
+        let encrypted = card().encrypt(&key).unwrap();
+        let decoded = STANDARD.decode(encrypted.as_str()).unwrap();
+        let plain = private.decrypt(Pkcs1v15Encrypt, &decoded).unwrap();
+
+        assert_eq!(plain, card().to_key_value().as_bytes());
+    }
+
+    #[test]
+    fn accepts_bare_base64_key() {
+        let private = RsaPrivateKey::new(&mut OsRng, 1024).unwrap();
+        let der = private.to_public_key().to_public_key_der().unwrap();
+
+        assert!(TerminalPublicKey::new(&STANDARD.encode(der.as_bytes())).is_ok());
+        assert!(matches!(
+            TerminalPublicKey::new("not a key"),
+            Err(Error::Config(_))
+        ));
+    }
+}
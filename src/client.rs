@@ -1,14 +1,22 @@
-use crate::Error;
-use serde::{Deserialize, Serialize};
+use crate::validate::{self, validated_string};
+use crate::{Error, Signable};
+use serde::Serialize;
+use std::any::type_name;
+use std::fmt;
 use std::time::Duration;
 use tracing::debug;
 
-pub const PRODUCTION_BASE: &str = "https://securepay.tinkoff/v2/Init";
-pub const TEST_BASE: &str = "https://rest-api-test.tinkoff/v2/Init";
+/// RU: Боевой адрес API. EN: Production API base URL.
+pub const PRODUCTION_BASE: &str = "https://securepay.tinkoff.ru";
+/// RU: Тестовый адрес API. EN: Test API base URL.
+pub const TEST_BASE: &str = "https://rest-api-test.tinkoff.ru";
 
+/// RU: Окружение API. EN: API environment.
 #[derive(Clone, Debug, Default)]
 pub enum Environment {
+    /// RU: Тестовое окружение. EN: Test environment.
     Test,
+    /// RU: Боевое окружение. EN: Production environment.
     #[default]
     Production,
 }
@@ -30,6 +38,7 @@ impl From<String> for Environment {
 }
 
 impl Environment {
+    /// RU: Базовый адрес окружения. EN: Base URL of the environment.
     pub fn base_url(&self) -> &'static str {
         match self {
             Environment::Production => PRODUCTION_BASE,
@@ -38,35 +47,129 @@ impl Environment {
     }
 }
 
+/// RU: Сервис API T‑Бизнес. EN: T-Business API service.
+#[derive(Clone, Copy, Debug)]
+pub enum Service {
+    /// RU: Интернет-эквайринг. EN: Internet acquiring.
+    Acquiring,
+    /// RU: Методы T‑Pay. EN: T-Pay methods.
+    TinkoffPay,
+    /// RU: Методы SberPay. EN: SberPay methods.
+    SberPay,
+    /// RU: Методы Mir Pay. EN: Mir Pay methods.
+    MirPay,
+}
+
+impl Service {
+    /// RU: Префикс пути сервиса, идет после версии. EN: Service path prefix, after the version.
+    pub fn path(&self) -> &'static str {
+        match self {
+            Service::Acquiring => "",
+            Service::TinkoffPay => "TinkoffPay",
+            Service::SberPay => "SberPay",
+            Service::MirPay => "MirPay",
+        }
+    }
+}
+
+/// RU: Версия API. EN: API version.
+#[derive(Clone, Copy, Debug, Default)]
+pub enum ApiVersion {
+    /// v2
+    #[default]
+    V2,
+}
+
+impl ApiVersion {
+    /// RU: Версия в виде сегмента пути. EN: Version as a path segment.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ApiVersion::V2 => "v2",
+        }
+    }
+}
+
+/// RU: HTTP-клиент эквайринга T‑Бизнес. EN: T-Business acquiring HTTP client.
+///
+/// Запросы SDK не содержат `TerminalKey` и `Token`: клиент добавляет их при отправке, см.
+/// [Signable].
 #[derive(Debug)]
 pub struct Client {
     pub(crate) client: reqwest::Client,
     env: Environment,
+    terminal_key: TerminalKey,
+    password: Password,
 }
 
 /// Requirements: <= 20 characters
 ///
 /// Идентификатор терминала. Выдается мерчанту в Т‑Бизнес при заведении терминала.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize)]
 pub struct TerminalKey(String);
 
+validated_string!(TerminalKey);
+
 impl TerminalKey {
+    /// RU: Ключ терминала из строки. EN: Terminal key from a string.
+    pub fn new(tk: impl Into<String>) -> Result<Self, Error> {
+        let tk = tk.into();
+        Self::check(&tk)?;
+        Ok(Self(tk))
+    }
+
+    /// RU: Значение ключа. EN: Terminal key value.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    fn check(value: &str) -> Result<(), Error> {
+        validate::length("TerminalKey", value, 1, 20)
+    }
+
     /// Gets and validates the terminal key from the environment
     fn from_env() -> Result<Self, Error> {
         let tk = std::env::var("TERMINAL_KEY")
+            .map_err(|_| Error::Config("TERMINAL_KEY variable is missing".to_string()))?;
+
+        Self::try_from(tk.as_str())
+    }
+}
+
+/// Пароль терминала. Выдается вместе с [TerminalKey] и используется только для подписи [Token](crate::Token).
+///
+/// Никогда не передается в запросах и не выводится в логи.
+#[derive(Clone)]
+pub struct Password(pub(crate) String);
+
+impl Password {
+    /// RU: Пароль из строки. EN: Password from a string.
+    pub fn new(password: impl Into<String>) -> Self {
+        Self(password.into())
+    }
+
+    /// Gets the terminal password from the environment
+    fn from_env() -> Result<Self, Error> {
+        let password = std::env::var("TERMINAL_PASSWORD")
             .ok()
-            .filter(|t| t.len() <= 20)
-            .ok_or_else(|| Error::Config("TERMINAL_KEY variable is missing".to_string()))?;
+            .filter(|p| !p.is_empty())
+            .ok_or_else(|| Error::Config("TERMINAL_PASSWORD variable is missing".to_string()))?;
 
-        Ok(Self(tk))
+        Ok(Self(password))
     }
 }
+
+impl fmt::Debug for Password {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Password(***)")
+    }
+}
+
 impl Client {
     /// Создать клиента для указанного окружения.  
     pub async fn new() -> Result<Self, Error> {
         let version = env!("CARGO_PKG_VERSION");
 
-        tracing_subscriber::fmt::init();
+        let _ = tracing_subscriber::fmt::try_init();
 
         debug!("Initializing T-Bank SDK client v{version}");
 
@@ -91,31 +194,89 @@ impl Client {
 
         debug!("Reqwest client constructed with standard timeouts");
 
-        Ok(Self { client, env })
+        let terminal_key = TerminalKey::from_env()?;
+        let password = Password::from_env()?;
+
+        Ok(Self {
+            client,
+            env,
+            terminal_key,
+            password,
+        })
+    }
+
+    /// RU: Ключ терминала, которым подписываются запросы.  
+    /// EN: Terminal key used to sign requests.
+    pub fn terminal_key(&self) -> &TerminalKey {
+        &self.terminal_key
     }
 }
 
 impl Client {
-    /// RU: Собрать полный URL для сервиса/версии/пути.  
-    /// EN: Build a fully-qualified URL for the given service, version and path.
+    /// RU: Собрать полный URL вида `{base}/{version}/{service}/{path}`; пустые сегменты
+    /// пропускаются.  
+    /// EN: Build a fully-qualified `{base}/{version}/{service}/{path}` URL, skipping empty
+    /// segments.
     pub fn url(&self, service: Service, version: ApiVersion, path: &str) -> String {
-        format!(
-            "{}{}/{}/{}",
-            self.env.base_url(),
-            service.path(),
-            version.as_str(),
-            path.trim_start_matches('/')
-        )
+        build_url(self.env.base_url(), service, version, path)
     }
 }
 
 impl Client {
-    /// RU: Отправить запрос: добавить авторизацию, проверить HTTP-статусы и десериализовать тело.  
-    /// EN: Send a request with auth, map HTTP errors, and deserialize the body.
+    /// RU: Подписать тело запроса и отправить POST в метод EACQ API.  
+    /// EN: Sign the request body and POST it to an EACQ API method.
+    pub async fn post<Req, Res>(&self, method: &str, req: &Req) -> Result<Res, Error>
+    where
+        Req: Serialize + ?Sized,
+        Res: serde::de::DeserializeOwned,
+    {
+        self.post_to(Service::Acquiring, method, req).await
+    }
+
+    /// RU: Подписать тело запроса и отправить POST в метод сервиса `service`.  
+    /// EN: Sign the request body and POST it to a method of `service`.
+    pub async fn post_to<Req, Res>(
+        &self,
+        service: Service,
+        method: &str,
+        req: &Req,
+    ) -> Result<Res, Error>
+    where
+        Req: Serialize + ?Sized,
+        Res: serde::de::DeserializeOwned,
+    {
+        let body = req.sign(&self.terminal_key, &self.password)?;
+        let url = self.url(service, ApiVersion::V2, method);
+
+        self.send(self.client.post(url).json(&body)).await
+    }
+
+    /// RU: Отправить GET с параметрами `query` по пути сервиса. Такие методы не подписываются.  
+    /// EN: Send an unsigned GET with `query` parameters to a service path.
+    pub async fn get<Q, Res>(&self, service: Service, path: &str, query: &Q) -> Result<Res, Error>
+    where
+        Q: Serialize + ?Sized,
+        Res: serde::de::DeserializeOwned,
+    {
+        let url = self.url(service, ApiVersion::V2, path);
+
+        self.send(self.client.get(url).query(query)).await
+    }
+
+    /// RU: Отправить запрос, проверить HTTP-статусы и десериализовать тело. Ответ с
+    /// `Success: false` превращается в [Error::Api].  
+    /// EN: Send a request, map HTTP errors, and deserialize the body. A `Success: false` body
+    /// becomes [Error::Api].
     pub async fn send<T>(&self, req: reqwest::RequestBuilder) -> Result<T, Error>
     where
         T: serde::de::DeserializeOwned,
     {
+        deserialize_response(self.send_raw(req).await?)
+    }
+
+    /// RU: Отправить запрос, проверить HTTP-статусы и вернуть тело как есть.  
+    /// EN: Send a request, map HTTP errors, and return the raw body.
+    pub async fn send_raw(&self, req: reqwest::RequestBuilder) -> Result<String, Error> {
         let request_snapshot = req.try_clone().and_then(|builder| builder.build().ok());
         if let Some(snapshot) = request_snapshot.as_ref() {
             debug!(
@@ -126,7 +287,7 @@ impl Client {
         } else {
             debug!("Sending request (unable to snapshot builder)");
         }
-        let resp = req.bearer_auth(&self.token).send().await.map_err(|e| {
+        let resp = req.send().await.map_err(|e| {
             if e.is_timeout() {
                 debug!("Request timed out: {e}");
                 Error::Timeout
@@ -176,31 +337,160 @@ impl Client {
 
         if !status.is_success() {
             debug!("API responded with non-success status {}", status);
-            return Err(Error::Api(body));
+            return Err(api_error(&body).unwrap_or(Error::Api {
+                code: status.as_str().to_string(),
+                message: body,
+                details: None,
+            }));
         }
 
-        // ------- Enhanced Deserialization --------
-        let mut deserializer = serde_json::Deserializer::from_str(&body);
+        Ok(body)
+    }
+}
 
-        match serde_path_to_error::deserialize::<_, T>(&mut deserializer) {
-            Ok(result) => {
-                debug!("Deserialization succeeded for {}", type_name::<T>());
-                Ok(result)
-            }
-            Err(err) => {
-                let path = err.path().to_string();
-                let inner = err.into_inner();
-                debug!(
-                    "Deserialization error for {} at {path}: {inner}",
-                    type_name::<T>()
-                );
+/// URL метода без привязки к клиенту, см. [Client::url].
+pub(crate) fn build_url(base: &str, service: Service, version: ApiVersion, path: &str) -> String {
+    let mut url = base.trim_end_matches('/').to_string();
+    for segment in [version.as_str(), service.path(), path] {
+        let segment = segment.trim_matches('/');
+        if !segment.is_empty() {
+            url.push('/');
+            url.push_str(segment);
+        }
+    }
+    url
+}
+
+/// Ошибка из тела ответа с `Success: false`.
+pub(crate) fn api_error(body: &str) -> Option<Error> {
+    let value: serde_json::Value = serde_json::from_str(body).ok()?;
+    if value.get("Success")?.as_bool()? {
+        return None;
+    }
+    let text = |key: &str| match value.get(key)? {
+        serde_json::Value::String(s) => Some(s.clone()),
+        serde_json::Value::Null => None,
+        other => Some(other.to_string()),
+    };
+    Some(Error::Api {
+        code: text("ErrorCode").unwrap_or_default(),
+        message: text("Message").unwrap_or_default(),
+        details: text("Details"),
+    })
+}
+
+/// Тело ответа API: `Success: false` — [Error::Api], иначе [deserialize].
+pub(crate) fn deserialize_response<T>(body: String) -> Result<T, Error>
+where
+    T: serde::de::DeserializeOwned,
+{
+    if let Some(err) = api_error(&body) {
+        debug!("API responded with Success: false");
+        return Err(err);
+    }
+    deserialize(body)
+}
+
+/// Тело ответа с SVG-изображением: `Success: false` — [Error::Api], не SVG — [Error::Deserialize].
+pub(crate) fn svg_response(body: String) -> Result<String, Error> {
+    if let Some(err) = api_error(&body) {
+        debug!("API responded with Success: false");
+        return Err(err);
+    }
+    let head = body.trim_start();
+    if !(head.starts_with("<svg") || head.starts_with("<?xml")) {
+        return Err(Error::Deserialize {
+            message: "expected an SVG image".to_string(),
+            path: ".".to_string(),
+            raw: body,
+        });
+    }
+    Ok(body)
+}
 
-                Err(Error::Deserialize {
-                    message: inner.to_string(),
-                    path,
-                    raw: body,
-                })
+/// RU: Десериализовать тело ответа с указанием пути до ошибки.  
+/// EN: Deserialize a response body, reporting the JSON path of any error.
+pub(crate) fn deserialize<T>(body: String) -> Result<T, Error>
+where
+    T: serde::de::DeserializeOwned,
+{
+    let mut deserializer = serde_json::Deserializer::from_str(&body);
+
+    match serde_path_to_error::deserialize::<_, T>(&mut deserializer) {
+        Ok(result) => {
+            debug!("Deserialization succeeded for {}", type_name::<T>());
+            Ok(result)
+        }
+        Err(err) => {
+            let path = err.path().to_string();
+            let inner = err.into_inner();
+            debug!(
+                "Deserialization error for {} at {path}: {inner}",
+                type_name::<T>()
+            );
+
+            Err(Error::Deserialize {
+                message: inner.to_string(),
+                path,
+                raw: body,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn url_shapes() {
+        assert_eq!(
+            build_url(PRODUCTION_BASE, Service::Acquiring, ApiVersion::V2, "Init"),
+            "https://securepay.tinkoff.ru/v2/Init"
+        );
+        assert_eq!(
+            build_url(
+                TEST_BASE,
+                Service::SberPay,
+                ApiVersion::V2,
+                "/transactions/700/link"
+            ),
+            "https://rest-api-test.tinkoff.ru/v2/SberPay/transactions/700/link"
+        );
+        assert_eq!(
+            build_url(
+                PRODUCTION_BASE,
+                Service::MirPay,
+                ApiVersion::V2,
+                "GetDeepLink"
+            ),
+            "https://securepay.tinkoff.ru/v2/MirPay/GetDeepLink"
+        );
+    }
+
+    #[test]
+    fn success_false_is_api_error() {
+        let body = r#"{"Success":false,"ErrorCode":"204","Message":"Неверный токен","Details":"Проверьте пару TerminalKey/Password"}"#;
+        match deserialize_response::<serde_json::Value>(body.to_string()) {
+            Err(Error::Api {
+                code,
+                message,
+                details,
+            }) => {
+                assert_eq!(code, "204");
+                assert_eq!(message, "Неверный токен");
+                assert_eq!(
+                    details.as_deref(),
+                    Some("Проверьте пару TerminalKey/Password")
+                );
             }
+            other => panic!("expected Error::Api, got {other:?}"),
         }
+
+        let ok: serde_json::Value =
+            deserialize_response(r#"{"Success":true,"ErrorCode":"0"}"#.to_string()).unwrap();
+        assert_eq!(ok["ErrorCode"], "0");
+        let svg: serde_json::Value = deserialize_response(r#""<svg/>""#.to_string()).unwrap();
+        assert_eq!(svg, "<svg/>");
     }
 }
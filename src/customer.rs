@@ -0,0 +1,150 @@
+use crate::{Client, CustomerKey, Email, Error, Phone, TerminalKey};
+use serde::{Deserialize, Serialize};
+use std::net::IpAddr;
+
+/// Запрос на регистрацию покупателя в системе Т‑Бизнес.
+///
+/// После регистрации за [CustomerKey] можно сохранять карты.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct AddCustomerReq {
+    customer_key: CustomerKey,
+    #[serde(rename = "IP", skip_serializing_if = "Option::is_none")]
+    ip: Option<IpAddr>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    email: Option<Email>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    phone: Option<Phone>,
+}
+
+impl AddCustomerReq {
+    /// RU: Зарегистрировать покупателя. EN: Register a customer.
+    pub fn new(customer_key: CustomerKey) -> Self {
+        Self {
+            customer_key,
+            ip: None,
+            email: None,
+            phone: None,
+        }
+    }
+
+    /// RU: IP-адрес покупателя. EN: Customer IP address.
+    pub fn ip(mut self, ip: IpAddr) -> Self {
+        self.ip = Some(ip);
+        self
+    }
+
+    /// RU: Электронная почта покупателя. EN: Customer email.
+    pub fn email(mut self, email: Email) -> Self {
+        self.email = Some(email);
+        self
+    }
+
+    /// RU: Телефон покупателя в формате +{Ц}. EN: Customer phone in +{digits} format.
+    pub fn phone(mut self, phone: Phone) -> Self {
+        self.phone = Some(phone);
+        self
+    }
+}
+
+/// Запрос по идентификатору покупателя.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+struct CustomerReq<'a> {
+    customer_key: &'a CustomerKey,
+}
+
+/// Ответ на регистрацию или удаление покупателя.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct CustomerRes {
+    /// Идентификатор терминала.
+    pub terminal_key: TerminalKey,
+    /// Идентификатор покупателя в системе мерчанта.
+    pub customer_key: CustomerKey,
+    /// Успешность прохождения запроса.
+    pub success: bool,
+    /// Код ошибки. `0` — если ошибки не произошло.
+    pub error_code: String,
+    /// Краткое описание ошибки.
+    pub message: Option<String>,
+    /// Подробное описание ошибки.
+    pub details: Option<String>,
+}
+
+/// Данные покупателя.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct GetCustomerRes {
+    /// Идентификатор терминала.
+    pub terminal_key: TerminalKey,
+    /// Идентификатор покупателя в системе мерчанта.
+    pub customer_key: CustomerKey,
+    /// Успешность прохождения запроса.
+    pub success: bool,
+    /// Код ошибки. `0` — если ошибки не произошло.
+    pub error_code: String,
+    /// Краткое описание ошибки.
+    pub message: Option<String>,
+    /// Подробное описание ошибки.
+    pub details: Option<String>,
+    /// Электронная почта покупателя.
+    pub email: Option<Email>,
+    /// Телефон покупателя.
+    pub phone: Option<Phone>,
+}
+
+impl Client {
+    /// RU: Зарегистрировать покупателя (`/v2/AddCustomer`).  
+    /// EN: Register a customer (`/v2/AddCustomer`).
+    pub async fn add_customer(&self, req: &AddCustomerReq) -> Result<CustomerRes, Error> {
+        self.post("AddCustomer", req).await
+    }
+
+    /// RU: Получить данные покупателя (`/v2/GetCustomer`).  
+    /// EN: Get customer data (`/v2/GetCustomer`).
+    pub async fn get_customer(&self, customer_key: &CustomerKey) -> Result<GetCustomerRes, Error> {
+        self.post("GetCustomer", &CustomerReq { customer_key })
+            .await
+    }
+
+    /// RU: Удалить покупателя вместе с сохраненными картами (`/v2/RemoveCustomer`).  
+    /// EN: Remove a customer and their saved cards (`/v2/RemoveCustomer`).
+    pub async fn remove_customer(&self, customer_key: &CustomerKey) -> Result<CustomerRes, Error> {
+        self.post("RemoveCustomer", &CustomerReq { customer_key })
+            .await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serialize_add_customer() {
+        let req = AddCustomerReq::new(CustomerKey::new("testCustomer1234").unwrap())
+            .ip("10.100.10.10".parse().unwrap())
+            .email(Email::new("a@test.ru").unwrap())
+            .phone(Phone::new("+71234567890").unwrap());
+
+        assert_eq!(
+            serde_json::to_value(&req).unwrap(),
+            serde_json::json!({
+                "CustomerKey": "testCustomer1234",
+                "IP": "10.100.10.10",
+                "Email": "a@test.ru",
+                "Phone": "+71234567890"
+            })
+        );
+    }
+
+    #[test]
+    fn parse_get_customer_response() {
+        let json = r#"{"TerminalKey":"TBankTest","CustomerKey":"testCustomer1234","Success":true,"ErrorCode":"0","Email":"a@test.ru","Phone":"+71234567890"}"#;
+        let res: GetCustomerRes = serde_json::from_str(json).unwrap();
+
+        assert!(res.success);
+        assert_eq!(res.customer_key.as_str(), "testCustomer1234");
+        assert!(res.email.is_some() && res.phone.is_some());
+    }
+}
@@ -1,3 +1,5 @@
+use crate::ChargeError;
+
 /// RU: Возможные ошибки SDK.  
 /// EN: All possible errors produced by the SDK.
 #[derive(Debug, thiserror::Error)]
@@ -34,9 +36,30 @@ pub enum Error {
     #[error("server error: {0}")]
     Server(String),
 
-    /// RU: Ошибка прикладного уровня API (код не 2xx). EN: API returned non-success response.
-    #[error("api error: {0}")]
-    Api(String),
+    /// RU: API отклонило запрос: `Success: false` или код ответа не 2xx. EN: API rejected the request:
+    /// `Success: false` or a non-2xx status.
+    #[error("api error {code}: {message}")]
+    Api {
+        /// RU: `ErrorCode` из ответа или HTTP-код. EN: `ErrorCode` from the body, or the HTTP status.
+        code: String,
+        /// RU: `Message` из ответа или тело целиком. EN: `Message` from the body, or the raw body.
+        message: String,
+        /// RU: `Details` из ответа. EN: `Details` from the body.
+        details: Option<String>,
+    },
+
+    /// RU: Подпись уведомления отсутствует или не совпадает. EN: Notification token is missing or does not match.
+    #[error("invalid notification signature")]
+    InvalidSignature,
+
+    /// RU: Значение не прошло проверку перед отправкой. EN: A value failed client-side validation.
+    #[error("invalid {field}: {reason}")]
+    Validation {
+        /// RU: Имя поля в API. EN: API field name.
+        field: &'static str,
+        /// RU: Причина. EN: Why the value was rejected.
+        reason: String,
+    },
 
     /// RU: Ошибка десериализации ответа API. EN: Failed to deserialize API response.
     #[error("deserialization error at {path}: {message}\nraw body: {raw}")]
@@ -50,6 +73,17 @@ pub enum Error {
     },
 }
 
+impl Error {
+    /// RU: Причина отказа в автоплатеже для [Error::Api], иначе `None`.
+    /// EN: Why a `Charge` was declined, for [Error::Api] only.
+    pub fn charge_error(&self) -> Option<ChargeError> {
+        match self {
+            Self::Api { code, .. } => Some(ChargeError::from(code.as_str())),
+            _ => None,
+        }
+    }
+}
+
 impl From<std::env::VarError> for Error {
     fn from(err: std::env::VarError) -> Self {
         Self::Config(err.to_string())
@@ -1,3 +1,6 @@
+//! RU: SDK интернет-эквайринга Т‑Бизнес.
+//!
+//! EN: Internet acquiring SDK for T-Business clients.
 #![deny(
     missing_debug_implementations,
     missing_docs,
@@ -9,12 +12,39 @@
     unused_results
 )]
 
+mod browser;
+mod card;
+mod card_data;
 mod client;
+mod customer;
 mod error;
+mod mirpay;
+mod notification;
 mod payment;
 mod receipt;
+mod sberpay;
+mod sbp;
+mod status;
+mod three_ds;
+mod token;
+mod tpay;
+mod validate;
+mod webhook;
 
+pub use browser::*;
+pub use card::*;
+pub use card_data::*;
 pub use client::*;
+pub use customer::*;
 pub use error::*;
+pub use mirpay::*;
+pub use notification::*;
 pub use payment::*;
 pub use receipt::*;
+pub use sberpay::*;
+pub use sbp::*;
+pub use status::*;
+pub use three_ds::*;
+pub use token::*;
+pub use tpay::*;
+pub use webhook::*;
@@ -0,0 +1,53 @@
+use crate::{Client, Error, PaymentId, Service};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+/// Запрос ссылки на оплату в Mir Pay.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+struct GetDeepLinkReq<'a> {
+    payment_id: &'a PaymentId,
+}
+
+/// Ответ на запрос ссылки Mir Pay.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct GetDeepLinkRes {
+    /// Ссылка для перехода в приложение Mir Pay.
+    pub deeplink: Option<Url>,
+    /// Успешность прохождения запроса.
+    pub success: bool,
+    /// Код ошибки. `0` — если ошибки не произошло.
+    pub error_code: String,
+    /// Краткое описание ошибки.
+    pub message: Option<String>,
+    /// Подробное описание ошибки.
+    pub details: Option<String>,
+}
+
+impl Client {
+    /// RU: Получить ссылку на оплату в Mir Pay (`/v2/MirPay/GetDeepLink`).
+    /// EN: Get the Mir Pay deeplink for a payment.
+    pub async fn mirpay_deeplink(&self, payment_id: &PaymentId) -> Result<GetDeepLinkRes, Error> {
+        self.post_to(
+            Service::MirPay,
+            "GetDeepLink",
+            &GetDeepLinkReq { payment_id },
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_deeplink() {
+        let json =
+            r#"{"Deeplink":"https://mirpay.nspk.ru/pay?data=abc","Success":true,"ErrorCode":"0"}"#;
+        let res: GetDeepLinkRes = serde_json::from_str(json).unwrap();
+
+        assert!(res.deeplink.is_some());
+    }
+}
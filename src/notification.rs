@@ -0,0 +1,413 @@
+use crate::{
+    AccountToken, Amount, BindingStatus, CardId, CustomerKey, Error, OrderId, Password, PaymentId,
+    PaymentStatus, RebillId, RequestKey, TerminalKey, Token, client::deserialize,
+};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer};
+use serde_json::{Map, Value};
+use subtle::ConstantTimeEq;
+use tracing::debug;
+
+/// Уведомление от Т‑Бизнес.
+///
+/// Т‑Бизнес отправляет его POST-запросом на [NotificationUrl](crate::NotificationUrl). В ответ
+/// мерчант должен вернуть HTTP 200 с телом `OK`, иначе уведомление будет отправлено повторно.
+///
+/// Вид уведомления определяется по `NotificationType` и `Status`. Уведомления неизвестной формы,
+/// а также те, что не разбираются в свой тип, не приводят к ошибке и попадают в
+/// [Notification::Raw].
+///
+/// [Подробнее](https://developer.tbank.ru/eacq/intro/developer/notification)
+#[derive(Debug)]
+pub enum Notification {
+    /// Изменение статуса платежа: AUTHORIZED, CONFIRMED, REVERSED, REFUNDED, REJECTED и другие.
+    Payment(PaymentNotification),
+    /// Результат привязки карты (`NotificationType` = LINKCARD).
+    AddCard(AddCardNotification),
+    /// Фискализация чека (`Status` = RECEIPT).
+    Receipt(ReceiptNotification),
+    /// Результат привязки счета СБП (`NotificationType` = LINKACCOUNT).
+    AccountQr(AccountQrNotification),
+    /// Уведомление неизвестной формы.
+    Raw(Map<String, Value>),
+}
+
+impl<'de> Deserialize<'de> for Notification {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let params = Map::<String, Value>::deserialize(deserializer)?;
+
+        let notification_type = params.get("NotificationType").and_then(Value::as_str);
+        let status = params.get("Status").and_then(Value::as_str);
+        let is_payment = ["PaymentId", "Status", "Amount"]
+            .iter()
+            .all(|key| params.contains_key(*key));
+
+        let kind = match (notification_type, status) {
+            (Some("LINKCARD"), _) => Kind::AddCard,
+            (Some("LINKACCOUNT"), _) => Kind::AccountQr,
+            (_, Some("RECEIPT")) => Kind::Receipt,
+            _ if is_payment => Kind::Payment,
+            _ => return Ok(Self::Raw(params)),
+        };
+
+        let value = Value::Object(params.clone());
+        let notification = match kind {
+            Kind::Payment => typed(value).map(Self::Payment),
+            Kind::AddCard => typed(value).map(Self::AddCard),
+            Kind::Receipt => typed(value).map(Self::Receipt),
+            Kind::AccountQr => typed(value).map(Self::AccountQr),
+        };
+
+        Ok(notification.unwrap_or_else(|err| {
+            debug!("Notification does not match its typed form, keeping it raw: {err}");
+            Self::Raw(params)
+        }))
+    }
+}
+
+/// Вид уведомления, определенный по его параметрам.
+enum Kind {
+    Payment,
+    AddCard,
+    Receipt,
+    AccountQr,
+}
+
+fn typed<T: DeserializeOwned>(value: Value) -> Result<T, serde_json::Error> {
+    T::deserialize(value)
+}
+
+/// Уведомление об изменении статуса платежа.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct PaymentNotification {
+    /// Идентификатор терминала.
+    pub terminal_key: TerminalKey,
+    /// Идентификатор заказа в системе мерчанта.
+    pub order_id: OrderId,
+    /// Успешность прохождения запроса.
+    pub success: bool,
+    /// Статус платежа.
+    pub status: PaymentStatus,
+    /// Идентификатор платежа в системе Т‑Бизнес.
+    pub payment_id: PaymentId,
+    /// Код ошибки. `0` — если ошибки не произошло.
+    pub error_code: String,
+    /// Сумма в копейках.
+    pub amount: Amount,
+    /// Идентификатор карты в системе Т‑Бизнес.
+    pub card_id: Option<CardId>,
+    /// Замаскированный номер карты или телефона.
+    pub pan: Option<String>,
+    /// Срок действия карты в формате MMYY.
+    pub exp_date: Option<String>,
+    /// Идентификатор автоплатежа, если платеж создан с `Recurrent`=Y.
+    pub rebill_id: Option<RebillId>,
+    /// Краткое описание ошибки.
+    pub message: Option<String>,
+    /// Подробное описание ошибки.
+    pub details: Option<String>,
+    /// Дополнительные параметры, переданные при инициации платежа.
+    #[serde(rename = "DATA")]
+    pub data: Option<Map<String, Value>>,
+    /// Подпись уведомления.
+    pub token: Token,
+}
+
+/// Уведомление о привязке карты.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct AddCardNotification {
+    /// Идентификатор терминала.
+    pub terminal_key: TerminalKey,
+    /// Идентификатор покупателя в системе мерчанта.
+    pub customer_key: CustomerKey,
+    /// Идентификатор запроса на привязку карты.
+    pub request_key: RequestKey,
+    /// Успешность привязки.
+    pub success: bool,
+    /// Статус привязки.
+    pub status: BindingStatus,
+    /// Идентификатор платежа, если привязка проходила со списанием.
+    pub payment_id: Option<PaymentId>,
+    /// Код ошибки. `0` — если ошибки не произошло.
+    pub error_code: String,
+    /// Идентификатор привязанной карты.
+    pub card_id: Option<CardId>,
+    /// Идентификатор автоплатежа, если карта привязана для рекуррентных платежей.
+    pub rebill_id: Option<RebillId>,
+    /// Замаскированный номер карты.
+    pub pan: Option<String>,
+    /// Срок действия карты в формате MMYY.
+    pub exp_date: Option<String>,
+    /// Краткое описание ошибки.
+    pub message: Option<String>,
+    /// Подробное описание ошибки.
+    pub details: Option<String>,
+    /// Подпись уведомления.
+    pub token: Token,
+}
+
+/// Уведомление о фискализации чека.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct ReceiptNotification {
+    /// Идентификатор терминала.
+    pub terminal_key: TerminalKey,
+    /// Идентификатор заказа в системе мерчанта.
+    pub order_id: OrderId,
+    /// Успешность фискализации.
+    pub success: bool,
+    /// Идентификатор платежа в системе Т‑Бизнес.
+    pub payment_id: PaymentId,
+    /// Код ошибки. `0` — если ошибки не произошло.
+    pub error_code: String,
+    /// Сумма чека в копейках.
+    pub amount: Amount,
+    /// Номер чека в смене.
+    pub receipt_number: Option<u32>,
+    /// Номер смены.
+    pub shift_number: Option<u32>,
+    /// Дата и время документа из ФН.
+    pub receipt_datetime: Option<String>,
+    /// Регистрационный номер ККТ.
+    pub ecr_reg_number: Option<String>,
+    /// Заводской номер фискального накопителя.
+    pub fn_number: Option<String>,
+    /// Фискальный номер документа.
+    pub fiscal_document_number: Option<u64>,
+    /// Фискальный признак документа.
+    pub fiscal_document_attribute: Option<u64>,
+    /// Признак расчета: Income, IncomeReturn.
+    #[serde(rename = "Type")]
+    pub kind: Option<String>,
+    /// Ссылка на чек.
+    pub url: Option<String>,
+    /// Ссылка на QR-код чека.
+    pub qr_code_url: Option<String>,
+    /// Краткое описание ошибки.
+    pub message: Option<String>,
+    /// Данные чека, переданные при инициации платежа.
+    pub receipt: Option<Map<String, Value>>,
+    /// Подпись уведомления.
+    pub token: Token,
+}
+
+/// Уведомление о привязке счета покупателя по СБП.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct AccountQrNotification {
+    /// Идентификатор терминала.
+    pub terminal_key: TerminalKey,
+    /// Идентификатор запроса на привязку счета.
+    pub request_key: RequestKey,
+    /// Успешность привязки.
+    pub success: bool,
+    /// Код ошибки. `0` — если ошибки не произошло.
+    pub error_code: String,
+    /// Идентификатор привязки счета, назначаемый банком-эмитентом.
+    pub account_token: Option<AccountToken>,
+    /// Идентификатор банка-эмитента клиента.
+    pub bank_member_id: Option<String>,
+    /// Наименование банка-эмитента.
+    pub bank_member_name: Option<String>,
+    /// Краткое описание ошибки.
+    pub message: Option<String>,
+    /// Подпись уведомления.
+    pub token: Token,
+}
+
+/// RU: Проверить подпись уведомления и разобрать его.
+/// EN: Verify the notification signature and parse it.
+///
+/// Токен пересчитывается по тем же правилам, что и для запросов: только параметры корневого
+/// уровня, булевы значения — `true`/`false`, вложенные объекты не участвуют. Сравнение
+/// выполняется за постоянное время.
+pub fn verify_notification(body: &[u8], password: &Password) -> Result<Notification, Error> {
+    let raw = String::from_utf8_lossy(body).into_owned();
+    let params: Map<String, Value> = deserialize(raw.clone())?;
+
+    let Some(Value::String(received)) = params.get("Token") else {
+        debug!("Notification has no Token");
+        return Err(Error::InvalidSignature);
+    };
+
+    let expected = Token::generate(&params, password);
+    if !bool::from(expected.as_str().as_bytes().ct_eq(received.as_bytes())) {
+        debug!("Notification token mismatch");
+        return Err(Error::InvalidSignature);
+    }
+
+    deserialize(raw)
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use super::*;
+
+    pub(crate) const PASSWORD: &str = "usaf8fw8fsw21g";
+
+    /// Тело уведомления `json` с подписью `Token`, рассчитанной по [PASSWORD].
+    pub(crate) fn signed(json: &str) -> Vec<u8> {
+        let Value::Object(mut params) = serde_json::from_str(json).unwrap() else {
+            panic!("notification is not an object");
+        };
+        let token = Token::generate(&params, &Password::new(PASSWORD));
+        let _ = params.insert(
+            "Token".to_string(),
+            Value::String(token.as_str().to_string()),
+        );
+
+        serde_json::to_vec(&params).unwrap()
+    }
+
+    #[test]
+    fn verifies_payment_notification() {
+        let body = signed(
+            r#"{"TerminalKey":"1321054611234DEMO","OrderId":"201709","Success":true,"Status":"AUTHORIZED","PaymentId":8742591,"ErrorCode":"0","Amount":9855,"CardId":322264,"Pan":"430000******0777","ExpDate":"1122","RebillId":145919,"DATA":{"Route":"TCB"}}"#,
+        );
+
+        let Notification::Payment(notification) =
+            verify_notification(&body, &Password::new(PASSWORD)).unwrap()
+        else {
+            panic!("expected a payment notification");
+        };
+
+        assert!(notification.success);
+        assert_eq!(notification.status, PaymentStatus::Authorized);
+        assert_eq!(notification.payment_id.as_str(), "8742591");
+        assert_eq!(notification.pan.as_deref(), Some("430000******0777"));
+        assert_eq!(
+            notification.rebill_id.as_ref().map(RebillId::as_str),
+            Some("145919")
+        );
+    }
+
+    #[test]
+    fn rejects_wrong_password() {
+        let body = signed(
+            r#"{"TerminalKey":"1321054611234DEMO","OrderId":"201709","Success":true,"Status":"CONFIRMED","PaymentId":8742591,"ErrorCode":"0","Amount":9855}"#,
+        );
+
+        assert!(matches!(
+            verify_notification(&body, &Password::new("wrong")),
+            Err(Error::InvalidSignature)
+        ));
+    }
+
+    #[test]
+    fn rejects_tampered_amount() {
+        let body = signed(
+            r#"{"TerminalKey":"1321054611234DEMO","OrderId":"201709","Success":true,"Status":"CONFIRMED","PaymentId":8742591,"ErrorCode":"0","Amount":9855}"#,
+        );
+        let tampered = String::from_utf8(body)
+            .unwrap()
+            .replace(r#""Amount":9855"#, r#""Amount":1"#);
+
+        assert!(matches!(
+            verify_notification(tampered.as_bytes(), &Password::new(PASSWORD)),
+            Err(Error::InvalidSignature)
+        ));
+    }
+
+    #[test]
+    fn rejects_missing_token() {
+        let body = br#"{"TerminalKey":"1321054611234DEMO","OrderId":"201709","Success":true}"#;
+
+        assert!(matches!(
+            verify_notification(body, &Password::new(PASSWORD)),
+            Err(Error::InvalidSignature)
+        ));
+    }
+
+    #[test]
+    fn parses_add_card_notification() {
+        let body = signed(
+            r#"{"TerminalKey":"1321054611234DEMO","CustomerKey":"906540","RequestKey":"ed989549-d3be-4758-95c7-22647e03f9ec","Success":true,"Status":"COMPLETED","PaymentId":"6155312072","ErrorCode":"0","CardId":"5555","Pan":"430000******0777","ExpDate":"1122","NotificationType":"LINKCARD"}"#,
+        );
+
+        let Notification::AddCard(notification) =
+            verify_notification(&body, &Password::new(PASSWORD)).unwrap()
+        else {
+            panic!("expected an AddCard notification");
+        };
+
+        assert_eq!(
+            notification.request_key.as_str(),
+            "ed989549-d3be-4758-95c7-22647e03f9ec"
+        );
+        assert_eq!(notification.status, BindingStatus::Completed);
+    }
+
+    #[test]
+    fn parses_receipt_notification() {
+        let body = signed(
+            r#"{"TerminalKey":"1321054611234DEMO","OrderId":"201709","Success":true,"Status":"RECEIPT","PaymentId":8742591,"ErrorCode":"0","Amount":9855,"FiscalNumber":1,"ShiftNumber":55,"ReceiptDatetime":"2017-08-07T13:13:00+03:00","ReceiptNumber":1,"FnNumber":"9999078900003012","EcrRegNumber":"0000000000026513","FiscalDocumentNumber":3,"FiscalDocumentAttribute":1302541524,"Type":"Income","Receipt":{"Taxation":"osn"}}"#,
+        );
+
+        let Notification::Receipt(notification) =
+            verify_notification(&body, &Password::new(PASSWORD)).unwrap()
+        else {
+            panic!("expected a receipt notification");
+        };
+
+        assert_eq!(notification.shift_number, Some(55));
+        assert_eq!(notification.kind.as_deref(), Some("Income"));
+    }
+
+    #[test]
+    fn parses_account_qr_notification() {
+        let body = signed(
+            r#"{"TerminalKey":"1321054611234DEMO","RequestKey":"13021e10-a3ed-4f14-bcd1-823b5ac37390","AccountToken":"a022254a5c3a4b4ca3a3a73b6b9d5fad","BankMemberId":"100000000004","BankMemberName":"Т-Банк","NotificationType":"LINKACCOUNT","Success":true,"ErrorCode":"0"}"#,
+        );
+
+        let Notification::AccountQr(notification) =
+            verify_notification(&body, &Password::new(PASSWORD)).unwrap()
+        else {
+            panic!("expected an AccountQr notification");
+        };
+
+        assert_eq!(notification.bank_member_id.as_deref(), Some("100000000004"));
+    }
+
+    #[test]
+    fn unknown_shape_falls_back_to_raw() {
+        let body =
+            signed(r#"{"TerminalKey":"1321054611234DEMO","Success":true,"Something":"new"}"#);
+
+        let Notification::Raw(params) =
+            verify_notification(&body, &Password::new(PASSWORD)).unwrap()
+        else {
+            panic!("expected a raw notification");
+        };
+
+        assert_eq!(params["Something"], "new");
+    }
+
+    #[test]
+    fn malformed_typed_falls_back_to_raw() {
+        let body = signed(
+            r#"{"TerminalKey":"1321054611234DEMO","OrderId":"201709","Success":true,"Status":"CONFIRMED","PaymentId":8742591,"ErrorCode":"0","Amount":"not a number"}"#,
+        );
+
+        let Notification::Raw(params) =
+            verify_notification(&body, &Password::new(PASSWORD)).unwrap()
+        else {
+            panic!("expected a raw notification");
+        };
+
+        assert_eq!(params["Status"], "CONFIRMED");
+        assert_eq!(params["Amount"], "not a number");
+    }
+
+    #[test]
+    fn rejects_malformed_json() {
+        assert!(matches!(
+            verify_notification(b"not json", &Password::new(PASSWORD)),
+            Err(Error::Deserialize { .. })
+        ));
+    }
+}
@@ -1,6 +1,12 @@
-use crate::{Receipt, TerminalKey};
-use chrono::{DateTime, Utc};
+use crate::validate::{self, validated_string};
+use crate::{
+    BrowserData, Client, Email, EncryptedCardData, Error, PaymentStatus, Receipt, Service,
+    TerminalKey,
+};
+use chrono::{DateTime, TimeDelta, Utc};
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::net::IpAddr;
 use std::num::NonZeroU32;
 use url::Url;
 
@@ -15,27 +21,95 @@ use url::Url;
 ///
 ///
 /// P.S. I'm not sure anyone will pay more than 42 949 672,96 RUB with this
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Amount(NonZeroU32);
 
+impl Amount {
+    /// RU: Минимальная сумма операции через СБП — 10 руб.
+    /// EN: Minimum SBP operation amount, 10 RUB.
+    pub const SBP_MIN: Self = Self(NonZeroU32::new(1000).unwrap());
+
+    /// RU: Сумма в копейках. EN: Amount from kopecks.
+    pub fn new(kopecks: NonZeroU32) -> Self {
+        Self(kopecks)
+    }
+
+    /// RU: Сумма в копейках. EN: Amount in kopecks.
+    pub fn kopecks(&self) -> u32 {
+        self.0.get()
+    }
+
+    /// RU: Проверить, что сумма не меньше минимальной для оплаты через СБП.
+    /// EN: Check that the amount meets the SBP minimum.
+    pub fn check_sbp(self) -> Result<Self, Error> {
+        if self < Self::SBP_MIN {
+            return Err(Error::Validation {
+                field: "Amount",
+                reason: format!(
+                    "SBP requires at least {} kopecks, got {}",
+                    Self::SBP_MIN.kopecks(),
+                    self.kopecks()
+                ),
+            });
+        }
+        Ok(self)
+    }
+}
+
 /// Requirements: <= 36 characters
 ///
 /// Идентификатор заказа в системе мерчанта. Должен быть уникальным для каждой операции.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
 pub struct OrderId(String);
 
-/// Подпись запроса
-#[derive(Serialize, Deserialize, Debug)]
-pub struct Token(String);
+validated_string!(OrderId);
+
+impl OrderId {
+    /// RU: Идентификатор заказа из строки. EN: Order identifier from a string.
+    pub fn new(id: impl Into<String>) -> Result<Self, Error> {
+        let id = id.into();
+        Self::check(&id)?;
+        Ok(Self(id))
+    }
+
+    /// RU: Идентификатор в виде строки. EN: Identifier as a string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    fn check(value: &str) -> Result<(), Error> {
+        validate::length("OrderId", value, 1, 36)
+    }
+}
 
 /// Requirements: <= 140 characters
 ///
 /// Описание заказа. Значение параметра будет отображено на платежной форме.
 ///
 // Параметр обязательный при привязке и одновременной оплате через СБП. При оплате через СБП текст из этого параметра отобразится в мобильном банке клиента.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Debug)]
 pub struct Description(String);
 
+validated_string!(Description);
+
+impl Description {
+    /// RU: Описание из строки. EN: Description from a string.
+    pub fn new(description: impl Into<String>) -> Result<Self, Error> {
+        let description = description.into();
+        Self::check(&description)?;
+        Ok(Self(description))
+    }
+
+    /// RU: Описание в виде строки. EN: Description as a string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    fn check(value: &str) -> Result<(), Error> {
+        validate::length("Description", value, 1, 140)
+    }
+}
+
 /// Requirements: <= 36 characters
 ///
 /// Идентификатор покупателя в системе мерчанта. Нужен для сохранения карт на платежной форме — платежи в один клик.
@@ -43,11 +117,67 @@ pub struct Description(String);
 /// Параметр обязательный, если передан параметр Recurrent=Y и автоплатеж проводится по карте.
 ///
 /// Если передан, в уведомлении будут указаны [CustomerKey] и его [CardId]. Подробнее — в методе [Получить список карт клиента](https://developer.tbank.ru/eacq/api/get-card-list).
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
 pub struct CustomerKey(String);
 
-#[derive(Serialize, Deserialize, Debug)]
-pub struct CardId(String);
+validated_string!(CustomerKey);
+
+impl CustomerKey {
+    /// RU: Идентификатор покупателя из строки. EN: Customer key from a string.
+    pub fn new(key: impl Into<String>) -> Result<Self, Error> {
+        let key = key.into();
+        Self::check(&key)?;
+        Ok(Self(key))
+    }
+
+    /// RU: Идентификатор в виде строки. EN: Customer key as a string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    fn check(value: &str) -> Result<(), Error> {
+        validate::length("CustomerKey", value, 1, 36)
+    }
+}
+
+/// Идентификатор карты в системе Т‑Бизнес.
+///
+/// В уведомлениях приходит числом, в ответах методов — строкой.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct CardId(#[serde(deserialize_with = "string_or_number")] String);
+
+impl CardId {
+    /// RU: Идентификатор карты из строки. EN: Card identifier from a string.
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+
+    /// RU: Идентификатор в виде строки. EN: Identifier as a string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Requirements: <= 20 characters
+///
+/// Идентификатор автоплатежа. Приходит в уведомлении AUTHORIZED, если платеж был создан
+/// с [Recurrent]=Y, и используется в методе [Провести платеж по сохраненным реквизитам](https://developer.tbank.ru/eacq/api/charge).
+///
+/// В уведомлениях приходит числом, в ответах методов — строкой.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct RebillId(#[serde(deserialize_with = "string_or_number")] String);
+
+impl RebillId {
+    /// RU: Идентификатор автоплатежа из строки. EN: Rebill identifier from a string.
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+
+    /// RU: Идентификатор в виде строки. EN: Identifier as a string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
 
 /// Requirements: <= 1 characters, [Y]
 ///
@@ -57,6 +187,13 @@ pub struct CardId(String);
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Recurrent(String);
 
+impl Recurrent {
+    /// RU: Сохранить реквизиты покупателя — `Y`. EN: Save the customer's credentials.
+    pub fn yes() -> Self {
+        Self("Y".to_string())
+    }
+}
+
 /// Requirements: [O, T]
 ///
 /// Определяет тип проведения платежа:
@@ -66,7 +203,9 @@ pub struct Recurrent(String);
 /// Если параметр передан, используется его значение, если нет — значение из настроек терминала.
 #[derive(Serialize, Deserialize, Debug)]
 pub enum PayType {
+    /// Одностадийная оплата.
     O,
+    /// Двухстадийная оплата.
     T,
 }
 
@@ -80,9 +219,12 @@ pub enum PayType {
 /// en — английский.
 /// Если параметр не передан, форма откроется на русском языке.
 #[derive(Default, Serialize, Deserialize, Debug)]
+#[serde(rename_all = "lowercase")]
 pub enum Language {
+    /// Русский.
     #[default]
     Ru,
+    /// Английский.
     En,
 }
 
@@ -119,9 +261,34 @@ pub struct FailUrl(Url);
 ///
 /// больше нуля — оно будет установлено в качестве срока жизни ссылки или динамического QR-кода;
 /// меньше нуля — устанавливается значение по умолчанию: 1440 мин. (1 сутки).
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
 pub struct RedirectDueDate(DateTime<Utc>);
 
+impl RedirectDueDate {
+    /// RU: Срок жизни из даты. EN: Lifetime from a date.
+    pub fn new(date: DateTime<Utc>) -> Self {
+        Self(date)
+    }
+
+    /// RU: Проверить, что срок жизни от 1 минуты до 90 дней от `now`.
+    /// EN: Check that the date is between 1 minute and 90 days after `now`.
+    pub fn validate_at(&self, now: DateTime<Utc>) -> Result<(), Error> {
+        if self.0 < now + TimeDelta::minutes(1) || self.0 > now + TimeDelta::days(90) {
+            return Err(Error::Validation {
+                field: "RedirectDueDate",
+                reason: format!("{} must be between 1 minute and 90 days from now", self.0),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl Serialize for RedirectDueDate {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&self.0.format("%Y-%m-%dT%H:%M:%S%:z"))
+    }
+}
+
 /// JSON-объект с дополнительными параметрами по операции и настройками в формате ключ:значение.
 ///
 /// Максимальная длина ключа — 20 знаков, значения — 100 знаков.
@@ -130,17 +297,138 @@ pub struct RedirectDueDate(DateTime<Utc>);
 ///
 /// Если ключи или значения содержат в себе специальные символы, получившееся значение должно быть закодировано функцией urlencode.
 /// ВНИМАНИЕ: SDK не имплементирует LongPay
-#[derive(Serialize, Deserialize, Debug)]
-pub struct Data {
-    additional_properties: String,
-    operation_initiator_type: OperationInitiatorType,
-    device: Device,
-    device_os: DeviceOs,
-    device_web_view: DeviceWebView,
-    device_browser: DeviceBrowser,
-    tinkoff_pay_web: TinkoffPayWeb,
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(transparent)]
+pub struct Data(Map<String, Value>);
+
+impl Data {
+    /// RU: Максимальное количество пар. EN: Maximum number of pairs.
+    pub const MAX_PAIRS: usize = 20;
+    /// RU: Максимальная длина ключа. Не относится к ключам, описанным в API, например
+    /// `OperationInitiatorType`. EN: Maximum length of a custom key.
+    pub const MAX_KEY_LEN: usize = 20;
+    /// RU: Максимальная длина значения. EN: Maximum value length.
+    pub const MAX_VALUE_LEN: usize = 100;
+
+    /// RU: Пустой объект `DATA`. EN: Empty `DATA` object.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// RU: Добавить произвольную пару ключ:значение. EN: Add an arbitrary key:value pair.
+    pub fn insert(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        let _ = self.0.insert(key.into(), Value::String(value.into()));
+        self
+    }
+
+    /// RU: Признак инициатора операции. EN: Operation initiator type.
+    pub fn operation_initiator_type(self, initiator: OperationInitiatorType) -> Self {
+        self.insert("OperationInitiatorType", initiator.as_str())
+    }
+
+    /// RU: Тип устройства. EN: Device type.
+    pub fn device(self, device: Device) -> Self {
+        let device = match device {
+            Device::Sdk => "SDK",
+            Device::Desktop => "Desktop",
+            Device::Mobile => "Mobile",
+        };
+        self.insert("Device", device)
+    }
+
+    /// RU: ОС устройства. EN: Device OS.
+    pub fn device_os(self, os: impl Into<String>) -> Self {
+        self.insert("DeviceOs", os)
+    }
+
+    /// RU: Признак открытия в WebView. EN: Whether the form is opened in a WebView.
+    pub fn device_web_view(self, web_view: bool) -> Self {
+        self.insert("DeviceWebView", web_view.to_string())
+    }
+
+    /// RU: Браузер. EN: Browser.
+    pub fn device_browser(self, browser: impl Into<String>) -> Self {
+        self.insert("DeviceBrowser", browser)
+    }
+
+    /// RU: Признак проведения операции через T‑Pay по API. EN: T-Pay via API flag.
+    pub fn tinkoff_pay_web(self, tinkoff_pay_web: bool) -> Self {
+        self.insert("TinkoffPayWeb", tinkoff_pay_web.to_string())
+    }
+
+    /// RU: Разрешить оплату по QR-коду СБП. Сумма платежа должна быть не меньше
+    /// [Amount::SBP_MIN]. EN: Allow paying with an SBP QR code; the amount must be at least
+    /// [Amount::SBP_MIN].
+    pub fn qr(self, qr: bool) -> Self {
+        self.insert("QR", qr.to_string())
+    }
+
+    /// Платеж запрошен с оплатой через СБП.
+    fn is_sbp(&self) -> bool {
+        self.0.get("QR").and_then(Value::as_str) == Some("true")
+    }
+
+    /// Добавить пары из `other`, заменяя совпадающие ключи.
+    pub(crate) fn merge(mut self, other: Data) -> Self {
+        self.0.extend(other.0);
+        self
+    }
+
+    /// RU: Количество пар. EN: Number of pairs.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// RU: Пар нет. EN: Whether there are no pairs.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// RU: Проверить ограничения на количество пар и длину ключей и значений.
+    /// EN: Check the pair count and key/value length limits.
+    pub fn validate(&self) -> Result<(), Error> {
+        let invalid = |reason| {
+            Err(Error::Validation {
+                field: "DATA",
+                reason,
+            })
+        };
+
+        if self.0.len() > Self::MAX_PAIRS {
+            return invalid(format!(
+                "at most {} pairs allowed, got {}",
+                Self::MAX_PAIRS,
+                self.0.len()
+            ));
+        }
+        for (key, value) in &self.0 {
+            if !DOCUMENTED_DATA_KEYS.contains(&key.as_str())
+                && key.chars().count() > Self::MAX_KEY_LEN
+            {
+                return invalid(format!(
+                    "key {key:?} is longer than {} characters",
+                    Self::MAX_KEY_LEN
+                ));
+            }
+            let len = match value {
+                Value::String(s) => s.chars().count(),
+                other => other.to_string().chars().count(),
+            };
+            if len > Self::MAX_VALUE_LEN {
+                return invalid(format!(
+                    "value of {key:?} is longer than {} characters",
+                    Self::MAX_VALUE_LEN
+                ));
+            }
+        }
+
+        Ok(())
+    }
 }
 
+/// Ключи `DATA`, описанные в API: на них ограничение длины ключа не распространяется.
+const DOCUMENTED_DATA_KEYS: &[&str] = &["OperationInitiatorType"];
+
 /// Requirements: [SDK, Desktop, Mobile]
 ///
 /// Тип устройства:
@@ -148,46 +436,71 @@ pub struct Data {
 /// SDK — вызов из мобильного приложения,
 /// Desktop — вызов из браузера с десктопа,
 /// Mobile — вызов из браузера с мобильного устройства.
-#[derive(Serialize, Deserialize, Debug)]
-pub struct Device(String);
-
-/// ОС устройства.
-#[derive(Serialize, Deserialize, Debug)]
-pub struct DeviceOs(String);
-
-/// Признак открытия в WebView.
-#[derive(Serialize, Deserialize, Debug)]
-pub struct DeviceWebView(bool);
-
-/// Браузер.
-#[derive(Serialize, Deserialize, Debug)]
-pub struct DeviceBrowser(String);
-
-/// Признак проведения операции через T‑Pay по API.
-#[derive(Serialize, Deserialize, Debug)]
-pub struct TinkoffPayWeb(bool);
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Device {
+    /// SDK — мобильное приложение.
+    #[serde(rename = "SDK")]
+    Sdk,
+    /// Desktop — браузер на десктопе.
+    Desktop,
+    /// Mobile — браузер на мобильном устройстве.
+    Mobile,
+}
 
 /// JSON-объект с данными маркетплейса. Параметр обязательный для маркетплейсов.
 #[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
 pub struct Shop {
     shop_code: ShopCode,
     amount: ShopAmount,
-    name: ShopName,
-    fee: Fee,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<ShopName>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fee: Option<Fee>,
+}
+
+impl Shop {
+    /// RU: Данные магазина с его долей суммы. EN: Marketplace shop with its share of the amount.
+    pub fn new(shop_code: impl Into<String>, amount: Amount) -> Self {
+        Self {
+            shop_code: ShopCode(shop_code.into()),
+            amount: ShopAmount(amount),
+            name: None,
+            fee: None,
+        }
+    }
+
+    /// RU: Наименование позиции. EN: Item name.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(ShopName(name.into()));
+        self
+    }
+
+    /// RU: Комиссия маркетплейса в копейках. EN: Marketplace fee in kopecks.
+    pub fn fee(mut self, fee: impl Into<String>) -> Self {
+        self.fee = Some(Fee(fee.into()));
+        self
+    }
 }
 
+/// Код магазина — `Submerchant_ID`, полученный при регистрации магазина.
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(transparent)]
 struct ShopCode(String);
 
+/// Сумма в копейках, которая относится к указанному [ShopCode].
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(transparent)]
 struct ShopAmount(Amount);
 
+/// Requirements: <= 128 characters
+///
+/// Наименование позиции.
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(transparent)]
 struct ShopName(String);
 
+/// Сумма комиссии в копейках, удерживаемая из возмещения партнера в пользу маркетплейса.
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(transparent)]
 struct Fee(String);
@@ -206,71 +519,1430 @@ struct Fee(String);
 /// D — MIT COF Delayed-Charge;
 /// N — MIT COF No-Show.
 /// Если передавать значения параметров, которые не соответствуют таблице, MAPI вернет ошибку 1126 — несопоставимые значения [rebillId] или [Recurrent] с переданным значением [OperationInitiatorType].
-#[derive(Serialize, Deserialize, Debug)]
-pub struct OperationInitiatorType;
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OperationInitiatorType {
+    /// 0 — обычный платеж.
+    #[serde(rename = "0")]
+    Regular,
+    /// 1 — CIT CC.
+    #[serde(rename = "1")]
+    CitCc,
+    /// 2 — CIT COF.
+    #[serde(rename = "2")]
+    CitCof,
+    /// R — MIT COF Recurring.
+    #[serde(rename = "R")]
+    MitRecurring,
+    /// I — MIT COF Installment.
+    #[serde(rename = "I")]
+    MitInstallment,
+    /// D — MIT COF Delayed-Charge.
+    #[serde(rename = "D")]
+    MitDelayedCharge,
+    /// N — MIT COF No-Show.
+    #[serde(rename = "N")]
+    MitNoShow,
+}
 
-/// Запрос для инициации платежа
-#[derive(Serialize)]
+impl OperationInitiatorType {
+    /// RU: Значение в том виде, в котором его передает API.
+    /// EN: Value as transmitted by the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Regular => "0",
+            Self::CitCc => "1",
+            Self::CitCof => "2",
+            Self::MitRecurring => "R",
+            Self::MitInstallment => "I",
+            Self::MitDelayedCharge => "D",
+            Self::MitNoShow => "N",
+        }
+    }
+}
+
+/// Запрос для инициации платежа.
+#[derive(Serialize, Debug)]
 #[serde(rename_all = "PascalCase")]
 pub struct InitPaymentReq {
-    terminal_key: TerminalKey,
     amount: Amount,
     order_id: OrderId,
-    token: Token,
+    #[serde(skip_serializing_if = "Option::is_none")]
     description: Option<Description>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     customer_key: Option<CustomerKey>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     recurrent: Option<Recurrent>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pay_type: Option<PayType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     language: Option<Language>,
+    #[serde(rename = "NotificationURL", skip_serializing_if = "Option::is_none")]
     notification_url: Option<NotificationUrl>,
+    #[serde(rename = "SuccessURL", skip_serializing_if = "Option::is_none")]
     success_url: Option<SuccessUrl>,
+    #[serde(rename = "FailURL", skip_serializing_if = "Option::is_none")]
     fail_url: Option<FailUrl>,
-    redirect_due_date: Option<DateTime<Utc>>,
-    #[serde(rename = "DATA")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    redirect_due_date: Option<RedirectDueDate>,
+    #[serde(rename = "DATA", skip_serializing_if = "Option::is_none")]
     data: Option<Data>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     receipt: Option<Receipt>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
     shops: Vec<Shop>,
 }
 
+impl InitPaymentReq {
+    /// RU: Начать сборку запроса с обязательными суммой и номером заказа.
+    /// EN: Start building a request with the required amount and order id.
+    pub fn builder(amount: Amount, order_id: OrderId) -> InitPaymentReqBuilder {
+        InitPaymentReqBuilder {
+            req: Self {
+                amount,
+                order_id,
+                description: None,
+                customer_key: None,
+                recurrent: None,
+                pay_type: None,
+                language: None,
+                notification_url: None,
+                success_url: None,
+                fail_url: None,
+                redirect_due_date: None,
+                data: None,
+                receipt: None,
+                shops: Vec::new(),
+            },
+        }
+    }
+}
+
+/// Сборщик [InitPaymentReq]. Ограничения API проверяются в [InitPaymentReqBuilder::build].
+#[derive(Debug)]
+pub struct InitPaymentReqBuilder {
+    req: InitPaymentReq,
+}
+
+impl InitPaymentReqBuilder {
+    /// RU: Описание заказа. EN: Order description.
+    pub fn description(mut self, description: Description) -> Self {
+        self.req.description = Some(description);
+        self
+    }
+
+    /// RU: Идентификатор покупателя. EN: Customer key.
+    pub fn customer_key(mut self, customer_key: CustomerKey) -> Self {
+        self.req.customer_key = Some(customer_key);
+        self
+    }
+
+    /// RU: Признак родительского рекуррентного платежа. EN: Parent recurring payment flag.
+    pub fn recurrent(mut self, recurrent: Recurrent) -> Self {
+        self.req.recurrent = Some(recurrent);
+        self
+    }
+
+    /// RU: Тип проведения платежа. EN: One- or two-stage payment.
+    pub fn pay_type(mut self, pay_type: PayType) -> Self {
+        self.req.pay_type = Some(pay_type);
+        self
+    }
+
+    /// RU: Язык платежной формы. EN: Payment form language.
+    pub fn language(mut self, language: Language) -> Self {
+        self.req.language = Some(language);
+        self
+    }
+
+    /// RU: Адрес для уведомлений. EN: Notification URL.
+    pub fn notification_url(mut self, url: Url) -> Self {
+        self.req.notification_url = Some(NotificationUrl(url));
+        self
+    }
+
+    /// RU: Адрес после успешной оплаты. EN: Redirect URL after a successful payment.
+    pub fn success_url(mut self, url: Url) -> Self {
+        self.req.success_url = Some(SuccessUrl(url));
+        self
+    }
+
+    /// RU: Адрес после неуспешной оплаты. EN: Redirect URL after a failed payment.
+    pub fn fail_url(mut self, url: Url) -> Self {
+        self.req.fail_url = Some(FailUrl(url));
+        self
+    }
+
+    /// RU: Срок жизни ссылки или QR-кода. EN: Payment link or QR code lifetime.
+    pub fn redirect_due_date(mut self, date: RedirectDueDate) -> Self {
+        self.req.redirect_due_date = Some(date);
+        self
+    }
+
+    /// RU: Дополнительные параметры `DATA`. EN: Additional `DATA` parameters.
+    pub fn data(mut self, data: Data) -> Self {
+        self.req.data = Some(data);
+        self
+    }
+
+    /// RU: Чек. EN: Receipt.
+    pub fn receipt(mut self, receipt: Receipt) -> Self {
+        self.req.receipt = Some(receipt);
+        self
+    }
+
+    /// RU: Данные маркетплейса. EN: Marketplace shops.
+    pub fn shops(mut self, shops: Vec<Shop>) -> Self {
+        self.req.shops = shops;
+        self
+    }
+
+    /// RU: Проверить ограничения API и получить запрос.
+    /// EN: Check the documented API limits and return the request.
+    pub fn build(self) -> Result<InitPaymentReq, Error> {
+        let req = self.req;
+
+        if let Some(date) = &req.redirect_due_date {
+            date.validate_at(Utc::now())?;
+        }
+        if let Some(data) = &req.data {
+            data.validate()?;
+            if data.is_sbp() {
+                let _ = req.amount.check_sbp()?;
+            }
+        }
+        if let Some(receipt) = &req.receipt {
+            receipt
+                .validate_against(req.amount)
+                .map_err(|errors| Error::Validation {
+                    field: "Receipt",
+                    reason: errors
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>()
+                        .join("; "),
+                })?;
+        }
+
+        Ok(req)
+    }
+}
+
 /// Ответ инициатора платежа
-#[derive(Deserialize)]
+#[derive(Deserialize, Debug)]
 #[serde(rename_all = "PascalCase")]
 pub struct InitPaymentRes {
     terminal_key: TerminalKey,
     amount: Amount,
     order_id: OrderId,
     success: bool,
-    status: Status,
+    status: PaymentStatus,
     payment_id: PaymentId,
     error_code: String,
+    #[serde(rename = "PaymentURL")]
     payment_url: Option<Url>,
-    message: String,
-    details: String,
+    message: Option<String>,
+    details: Option<String>,
 }
 
-/// Requirements: <= 20 characters
+impl InitPaymentRes {
+    /// RU: Ключ терминала. EN: Terminal key.
+    pub fn terminal_key(&self) -> &TerminalKey {
+        &self.terminal_key
+    }
+
+    /// RU: Сумма в копейках. EN: Amount in kopecks.
+    pub fn amount(&self) -> &Amount {
+        &self.amount
+    }
+
+    /// RU: Идентификатор заказа. EN: Order identifier.
+    pub fn order_id(&self) -> &OrderId {
+        &self.order_id
+    }
+
+    /// RU: Успешность прохождения запроса. EN: Whether the request succeeded.
+    pub fn success(&self) -> bool {
+        self.success
+    }
+
+    /// RU: Статус платежа. EN: Payment status.
+    pub fn status(&self) -> &PaymentStatus {
+        &self.status
+    }
+
+    /// RU: Идентификатор платежа. EN: Payment identifier.
+    pub fn payment_id(&self) -> &PaymentId {
+        &self.payment_id
+    }
+
+    /// RU: Код ошибки, `0` при успехе. EN: Error code, `0` on success.
+    pub fn error_code(&self) -> &str {
+        &self.error_code
+    }
+
+    /// RU: Ссылка на платежную форму. EN: Hosted payment form URL.
+    pub fn payment_url(&self) -> Option<&Url> {
+        self.payment_url.as_ref()
+    }
+
+    /// RU: Краткое описание ошибки. EN: Short error description.
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+
+    /// RU: Подробное описание ошибки. EN: Detailed error description.
+    pub fn details(&self) -> Option<&str> {
+        self.details.as_deref()
+    }
+}
+
+/// Запрос статуса платежа.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+struct GetStateReq<'a> {
+    payment_id: &'a PaymentId,
+}
+
+/// Ответ на запрос статуса платежа.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct GetStateRes {
+    /// Идентификатор терминала.
+    pub terminal_key: TerminalKey,
+    /// Сумма в копейках.
+    pub amount: Option<Amount>,
+    /// Идентификатор заказа в системе мерчанта.
+    pub order_id: OrderId,
+    /// Успешность прохождения запроса.
+    pub success: bool,
+    /// Статус платежа.
+    pub status: PaymentStatus,
+    /// Идентификатор платежа в системе Т‑Бизнес.
+    pub payment_id: PaymentId,
+    /// Код ошибки. `0` — если ошибки не произошло.
+    pub error_code: String,
+    /// Краткое описание ошибки.
+    pub message: Option<String>,
+    /// Подробное описание ошибки.
+    pub details: Option<String>,
+    /// Информация о платеже, например `Route` и `Source`.
+    #[serde(default)]
+    pub params: Vec<StateParam>,
+}
+
+/// Запрос на подтверждение двухстадийного платежа.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct ConfirmReq {
+    payment_id: PaymentId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    amount: Option<Amount>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    receipt: Option<Receipt>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    shops: Vec<Shop>,
+}
+
+impl ConfirmReq {
+    /// RU: Подтвердить платеж на всю заблокированную сумму.
+    /// EN: Confirm the payment for the whole authorized amount.
+    pub fn new(payment_id: PaymentId) -> Self {
+        Self {
+            payment_id,
+            amount: None,
+            receipt: None,
+            shops: Vec::new(),
+        }
+    }
+
+    /// RU: Сумма подтверждения, если она меньше суммы авторизации.
+    /// EN: Partial confirmation amount.
+    pub fn amount(mut self, amount: Amount) -> Self {
+        self.amount = Some(amount);
+        self
+    }
+
+    /// RU: Чек, если состав заказа изменился.
+    /// EN: Updated receipt, when the order contents changed.
+    pub fn receipt(mut self, receipt: Receipt) -> Self {
+        self.receipt = Some(receipt);
+        self
+    }
+
+    /// RU: Данные маркетплейса. EN: Marketplace shops.
+    pub fn shops(mut self, shops: Vec<Shop>) -> Self {
+        self.shops = shops;
+        self
+    }
+}
+
+/// Ответ на подтверждение платежа.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct ConfirmRes {
+    /// Идентификатор терминала.
+    pub terminal_key: TerminalKey,
+    /// Идентификатор заказа в системе мерчанта.
+    pub order_id: OrderId,
+    /// Успешность прохождения запроса.
+    pub success: bool,
+    /// Статус платежа.
+    pub status: PaymentStatus,
+    /// Идентификатор платежа в системе Т‑Бизнес.
+    pub payment_id: PaymentId,
+    /// Код ошибки. `0` — если ошибки не произошло.
+    pub error_code: String,
+    /// Краткое описание ошибки.
+    pub message: Option<String>,
+    /// Подробное описание ошибки.
+    pub details: Option<String>,
+}
+
+/// Запрос на отмену платежа: отмену авторизации или полный/частичный возврат.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct CancelReq {
+    payment_id: PaymentId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    amount: Option<Amount>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    receipt: Option<Receipt>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    shops: Vec<Shop>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    qr_member_id: Option<QrMemberId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    route: Option<Route>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    source: Option<Source>,
+}
+
+impl CancelReq {
+    /// RU: Отменить платеж на всю сумму.
+    /// EN: Cancel the payment for its whole amount.
+    pub fn new(payment_id: PaymentId) -> Self {
+        Self {
+            payment_id,
+            amount: None,
+            receipt: None,
+            shops: Vec::new(),
+            qr_member_id: None,
+            route: None,
+            source: None,
+        }
+    }
+
+    /// RU: Сумма частичной отмены или возврата.
+    /// EN: Partial reversal or refund amount.
+    pub fn amount(mut self, amount: Amount) -> Self {
+        self.amount = Some(amount);
+        self
+    }
+
+    /// RU: Чек возврата. Обязателен при частичном возврате, если подключена онлайн-касса.
+    /// EN: Refund receipt; required for partial refunds with an online cash register.
+    pub fn receipt(mut self, receipt: Receipt) -> Self {
+        self.receipt = Some(receipt);
+        self
+    }
+
+    /// RU: Данные маркетплейса. EN: Marketplace shops.
+    pub fn shops(mut self, shops: Vec<Shop>) -> Self {
+        self.shops = shops;
+        self
+    }
+
+    /// RU: Банк, в который вернуть деньги при возврате по СБП.
+    /// EN: Bank to refund to for SBP payments.
+    pub fn qr_member_id(mut self, qr_member_id: QrMemberId) -> Self {
+        self.qr_member_id = Some(qr_member_id);
+        self
+    }
+
+    /// RU: Способ платежа — для возврата рассрочки.
+    /// EN: Payment route, for installment refunds.
+    pub fn route(mut self, route: Route) -> Self {
+        self.route = Some(route);
+        self
+    }
+
+    /// RU: Источник платежа — для возврата рассрочки.
+    /// EN: Payment source, for installment refunds.
+    pub fn source(mut self, source: Source) -> Self {
+        self.source = Some(source);
+        self
+    }
+}
+
+/// Идентификатор банка-участника СБП.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct QrMemberId(String);
+
+impl QrMemberId {
+    /// RU: Идентификатор банка из строки. EN: Bank identifier from a string.
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+
+    /// RU: Идентификатор в виде строки. EN: Identifier as a string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Requirements: [TCB, BNPL]
 ///
-/// Статус транзакции.
-#[derive(Deserialize)]
-struct Status(String);
+/// Способ платежа. Обязателен для возврата платежа в рассрочку.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Route {
+    /// TCB — Т‑Банк.
+    #[serde(rename = "TCB")]
+    Tcb,
+    /// BNPL — «Долями».
+    #[serde(rename = "BNPL")]
+    Bnpl,
+}
+
+/// Requirements: [installment, BNPL]
+///
+/// Источник платежа. Обязателен для возврата платежа в рассрочку.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Source {
+    /// installment — рассрочка.
+    #[serde(rename = "installment")]
+    Installment,
+    /// BNPL — «Долями».
+    #[serde(rename = "BNPL")]
+    Bnpl,
+}
+
+/// Ответ на отмену платежа.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct CancelRes {
+    /// Идентификатор терминала.
+    pub terminal_key: TerminalKey,
+    /// Идентификатор заказа в системе мерчанта.
+    pub order_id: OrderId,
+    /// Успешность прохождения запроса.
+    pub success: bool,
+    /// Статус платежа после отмены.
+    pub status: PaymentStatus,
+    /// Сумма до отмены в копейках.
+    pub original_amount: u32,
+    /// Сумма после отмены в копейках.
+    pub new_amount: u32,
+    /// Идентификатор платежа в системе Т‑Бизнес.
+    pub payment_id: PaymentId,
+    /// Код ошибки. `0` — если ошибки не произошло.
+    pub error_code: String,
+    /// Краткое описание ошибки.
+    pub message: Option<String>,
+    /// Подробное описание ошибки.
+    pub details: Option<String>,
+}
+
+/// Результат отмены платежа.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CancelOutcome {
+    /// Полная отмена авторизации — средства разблокированы.
+    Reversal,
+    /// Частичная отмена авторизации.
+    PartialReversal,
+    /// Полный возврат списанных средств.
+    Refund,
+    /// Частичный возврат.
+    PartialRefund,
+    /// Статус не относится к отмене, например платеж в статусе NEW.
+    Other,
+}
+
+impl CancelRes {
+    /// RU: Что произошло с платежом: отмена авторизации или возврат, полностью или частично.
+    /// EN: Whether the payment was reversed or refunded, fully or partially.
+    pub fn outcome(&self) -> CancelOutcome {
+        let partial = self.new_amount > 0 && self.new_amount < self.original_amount;
+
+        match (&self.status, partial) {
+            (PaymentStatus::PartialReversed, _) => CancelOutcome::PartialReversal,
+            (PaymentStatus::Reversing | PaymentStatus::Reversed, true) => {
+                CancelOutcome::PartialReversal
+            }
+            (PaymentStatus::Reversing | PaymentStatus::Reversed, false) => CancelOutcome::Reversal,
+            (PaymentStatus::PartialRefunded, _) => CancelOutcome::PartialRefund,
+            (PaymentStatus::Refunding | PaymentStatus::Refunded, true) => {
+                CancelOutcome::PartialRefund
+            }
+            (PaymentStatus::Refunding | PaymentStatus::Refunded, false) => CancelOutcome::Refund,
+            _ => CancelOutcome::Other,
+        }
+    }
+
+    /// RU: Отмененная сумма в копейках. EN: Cancelled amount in kopecks.
+    pub fn cancelled_amount(&self) -> u32 {
+        self.original_amount.saturating_sub(self.new_amount)
+    }
+}
+
+/// Запрос статуса заказа.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+struct CheckOrderReq<'a> {
+    order_id: &'a OrderId,
+}
+
+/// Ответ со всеми операциями по заказу.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct CheckOrderRes {
+    /// Идентификатор терминала.
+    pub terminal_key: TerminalKey,
+    /// Идентификатор заказа в системе мерчанта.
+    pub order_id: OrderId,
+    /// Успешность прохождения запроса.
+    pub success: bool,
+    /// Код ошибки. `0` — если ошибки не произошло.
+    pub error_code: String,
+    /// Краткое описание ошибки.
+    pub message: Option<String>,
+    /// Подробное описание ошибки.
+    pub details: Option<String>,
+    /// Все платежи, созданные с этим `OrderId`.
+    #[serde(default)]
+    pub payments: Vec<OrderPayment>,
+}
+
+impl CheckOrderRes {
+    /// RU: Первая прошедшая попытка оплаты заказа, см. [PaymentStatus::is_successful].
+    /// EN: The first attempt that went through, see [PaymentStatus::is_successful].
+    pub fn successful_payment(&self) -> Option<&OrderPayment> {
+        self.payments.iter().find(|p| p.status.is_successful())
+    }
+}
+
+/// Платеж из ответа `CheckOrder`.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct OrderPayment {
+    /// Идентификатор платежа в системе Т‑Бизнес.
+    pub payment_id: PaymentId,
+    /// Сумма в копейках.
+    pub amount: Option<Amount>,
+    /// Статус платежа.
+    pub status: PaymentStatus,
+    /// Reference Retrieval Number — идентификатор операции в платежной системе.
+    #[serde(rename = "RRN")]
+    pub rrn: Option<String>,
+    /// Успешность операции.
+    pub success: bool,
+    /// Код ошибки. `0` — если ошибки не произошло.
+    pub error_code: String,
+    /// Краткое описание ошибки.
+    pub message: Option<String>,
+}
+
+/// Запрос на автоплатеж по сохраненной карте.
+///
+/// Сначала создается платеж методом `Init`, затем его `PaymentId` передается сюда вместе с
+/// [RebillId] родительского платежа.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct ChargeReq {
+    payment_id: PaymentId,
+    rebill_id: RebillId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    send_email: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    info_email: Option<Email>,
+}
+
+impl ChargeReq {
+    /// RU: Списать платеж `payment_id` с карты автоплатежа `rebill_id`.
+    /// EN: Charge `payment_id` against the card saved as `rebill_id`.
+    pub fn new(payment_id: PaymentId, rebill_id: RebillId) -> Self {
+        Self {
+            payment_id,
+            rebill_id,
+            send_email: None,
+            info_email: None,
+        }
+    }
+
+    /// RU: Отправить покупателю письмо об успешном платеже на `email`.
+    /// EN: Email the customer about the successful payment.
+    pub fn send_email(mut self, email: Email) -> Self {
+        self.send_email = Some(true);
+        self.info_email = Some(email);
+        self
+    }
+}
+
+/// Ответ на автоплатеж.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct ChargeRes {
+    /// Идентификатор терминала.
+    pub terminal_key: TerminalKey,
+    /// Сумма в копейках.
+    pub amount: Option<Amount>,
+    /// Идентификатор заказа в системе мерчанта.
+    pub order_id: OrderId,
+    /// Успешность прохождения запроса.
+    pub success: bool,
+    /// Статус платежа.
+    pub status: PaymentStatus,
+    /// Идентификатор платежа в системе Т‑Бизнес.
+    pub payment_id: PaymentId,
+    /// Код ошибки. `0` — если ошибки не произошло.
+    pub error_code: String,
+    /// Краткое описание ошибки.
+    pub message: Option<String>,
+    /// Подробное описание ошибки.
+    pub details: Option<String>,
+}
+
+/// Причина отказа в автоплатеже по коду ошибки `ErrorCode`.
+///
+/// Коды вида `10XX` — ответ банка-эмитента `XX`.
+///
+/// [Подробнее](https://developer.tbank.ru/eacq/appendixes/errors)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChargeError {
+    /// 116, 1051 — недостаточно средств на карте.
+    InsufficientFunds,
+    /// 1054 — истек срок действия карты.
+    ExpiredCard,
+    /// 1014 — неверный номер карты.
+    InvalidCard,
+    /// 1041, 1043 — карта утеряна или украдена.
+    LostOrStolenCard,
+    /// 1057, 1058 — операция запрещена для карты или терминала.
+    NotPermitted,
+    /// 1061, 1065 — превышен лимит суммы или количества операций.
+    LimitExceeded,
+    /// 1005 — банк-эмитент отклонил операцию без объяснения причин.
+    DoNotHonor,
+    /// 1091, 1096 — банк-эмитент недоступен.
+    IssuerUnavailable,
+    /// 1126 — `RebillId` или `Recurrent` не сопоставимы с [OperationInitiatorType].
+    InitiatorMismatch,
+    /// Другой код ошибки.
+    Other(String),
+}
+
+impl ChargeError {
+    /// RU: Имеет смысл повторить автоплатеж позже с той же картой.
+    /// EN: Retrying the same card later may succeed.
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            Self::InsufficientFunds
+                | Self::LimitExceeded
+                | Self::DoNotHonor
+                | Self::IssuerUnavailable
+        )
+    }
+}
+
+impl From<&str> for ChargeError {
+    fn from(code: &str) -> Self {
+        match code {
+            "116" | "1051" => Self::InsufficientFunds,
+            "1054" => Self::ExpiredCard,
+            "1014" => Self::InvalidCard,
+            "1041" | "1043" => Self::LostOrStolenCard,
+            "1057" | "1058" => Self::NotPermitted,
+            "1061" | "1065" => Self::LimitExceeded,
+            "1005" => Self::DoNotHonor,
+            "1091" | "1096" => Self::IssuerUnavailable,
+            "1126" => Self::InitiatorMismatch,
+            other => Self::Other(other.to_string()),
+        }
+    }
+}
+
+/// Запрос на оплату с собственной платежной формы мерчанта.
+///
+/// Платеж создается методом `Init`, затем сюда передаются данные карты, зашифрованные
+/// открытым ключом терминала.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct FinishAuthorizeReq {
+    payment_id: PaymentId,
+    card_data: EncryptedCardData,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    amount: Option<Amount>,
+    #[serde(rename = "IP", skip_serializing_if = "Option::is_none")]
+    ip: Option<IpAddr>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    send_email: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    info_email: Option<Email>,
+    #[serde(rename = "DATA", skip_serializing_if = "Option::is_none")]
+    data: Option<Data>,
+}
+
+impl FinishAuthorizeReq {
+    /// RU: Оплатить платеж `payment_id` картой, см. [CardData::encrypt](crate::CardData::encrypt).
+    /// EN: Pay `payment_id` with encrypted card data.
+    pub fn new(payment_id: PaymentId, card_data: EncryptedCardData) -> Self {
+        Self {
+            payment_id,
+            card_data,
+            amount: None,
+            ip: None,
+            send_email: None,
+            info_email: None,
+            data: None,
+        }
+    }
+
+    /// RU: Сумма в копейках, если она отличается от суммы в `Init`.
+    /// EN: Amount, when it differs from the one passed to `Init`.
+    pub fn amount(mut self, amount: Amount) -> Self {
+        self.amount = Some(amount);
+        self
+    }
+
+    /// RU: IP-адрес покупателя. EN: Customer IP address.
+    pub fn ip(mut self, ip: IpAddr) -> Self {
+        self.ip = Some(ip);
+        self
+    }
+
+    /// RU: Отправить покупателю письмо об успешном платеже на `email`.
+    /// EN: Email the customer about the successful payment.
+    pub fn send_email(mut self, email: Email) -> Self {
+        self.send_email = Some(true);
+        self.info_email = Some(email);
+        self
+    }
+
+    /// RU: Дополнительные параметры, например данные браузера для 3DS.
+    /// EN: Additional parameters, e.g. browser data for 3DS.
+    pub fn data(mut self, data: Data) -> Self {
+        self.data = Some(data);
+        self
+    }
+
+    /// RU: Данные браузера для 3-D Secure v2, добавляются к `DATA`.
+    /// EN: Browser data for 3-D Secure v2, merged into `DATA`.
+    pub fn browser_data(mut self, browser: &BrowserData) -> Self {
+        let data = self.data.take().unwrap_or_default();
+        self.data = Some(data.merge(browser.to_data()));
+        self
+    }
+}
+
+/// Ответ на оплату с собственной платежной формы.
+///
+/// Если карта вовлечена в 3-D Secure, платеж получает статус 3DS_CHECKING и ответ содержит
+/// параметры для перехода на страницу ACS.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct FinishAuthorizeRes {
+    /// Идентификатор терминала.
+    pub terminal_key: TerminalKey,
+    /// Сумма в копейках.
+    pub amount: Option<Amount>,
+    /// Идентификатор заказа в системе мерчанта.
+    pub order_id: Option<OrderId>,
+    /// Успешность прохождения запроса.
+    pub success: bool,
+    /// Статус платежа.
+    pub status: PaymentStatus,
+    /// Идентификатор платежа в системе Т‑Бизнес.
+    pub payment_id: PaymentId,
+    /// Код ошибки. `0` — если ошибки не произошло.
+    pub error_code: String,
+    /// Краткое описание ошибки.
+    pub message: Option<String>,
+    /// Подробное описание ошибки.
+    pub details: Option<String>,
+    /// Идентификатор автоплатежа.
+    pub rebill_id: Option<RebillId>,
+    /// Идентификатор карты в системе Т‑Бизнес.
+    pub card_id: Option<CardId>,
+    /// Адрес сервера ACS для проверки 3-D Secure.
+    #[serde(rename = "ACSUrl")]
+    pub acs_url: Option<Url>,
+    /// Уникальный идентификатор транзакции 3-D Secure v1.
+    #[serde(rename = "MD")]
+    pub md: Option<String>,
+    /// Данные запроса 3-D Secure v1.
+    pub pa_req: Option<String>,
+    /// Идентификатор транзакции 3-D Secure v2 на сервере 3DS.
+    #[serde(rename = "TdsServerTransId")]
+    pub tds_server_trans_id: Option<String>,
+    /// Идентификатор транзакции 3-D Secure v2 на сервере ACS.
+    pub acs_trans_id: Option<String>,
+}
+
+/// Дополнительный параметр платежа из ответа `GetState`.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct StateParam {
+    /// Ключ.
+    pub key: String,
+    /// Значение.
+    pub value: String,
+}
 
 /// Requirements: <= 20 characters
 ///
 /// Идентификатор платежа в системе Т‑Бизнес.
-#[derive(Deserialize)]
-struct PaymentId(String);
+///
+/// В уведомлениях приходит числом, в ответах методов — строкой.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PaymentId(#[serde(deserialize_with = "string_or_number")] String);
+
+impl PaymentId {
+    /// RU: Идентификатор платежа из строки. EN: Payment identifier from a string.
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+
+    /// RU: Идентификатор в виде строки. EN: Identifier as a string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Идентификаторы Т‑Бизнес приходят то строкой, то числом — приводим к строке.
+pub(crate) fn string_or_number<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Raw {
+        String(String),
+        Number(serde_json::Number),
+    }
 
+    Ok(match Raw::deserialize(deserializer)? {
+        Raw::String(s) => s,
+        Raw::Number(n) => n.to_string(),
+    })
+}
+
+/// Requirements: [API, SDK]
+///
+/// Откуда проводится оплата: через API или мобильный SDK.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaySource {
+    /// API — собственная платежная форма.
+    #[serde(rename = "API")]
+    Api,
+    /// SDK — мобильный SDK.
+    #[serde(rename = "SDK")]
+    Sdk,
+}
+
+/// Запрос способов оплаты, подключенных на терминале.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+struct GetTerminalPayMethodsReq<'a> {
+    terminal_key: &'a TerminalKey,
+    pay_source: PaySource,
+}
+
+/// Способ оплаты.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PayMethod {
+    /// T‑Pay.
+    TinkoffPay,
+    /// SberPay.
+    SberPay,
+    /// Mir Pay.
+    MirPay,
+    /// СБП.
+    Sbp,
+    /// Yandex Pay.
+    YandexPay,
+    /// Способ оплаты, неизвестный SDK.
+    Unknown(String),
+}
+
+impl From<&str> for PayMethod {
+    fn from(s: &str) -> Self {
+        match s {
+            "TinkoffPay" => Self::TinkoffPay,
+            "SberPay" => Self::SberPay,
+            "MirPay" => Self::MirPay,
+            "SBP" => Self::Sbp,
+            "YandexPay" => Self::YandexPay,
+            other => Self::Unknown(other.to_string()),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for PayMethod {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Ok(Self::from(s.as_str()))
+    }
+}
+
+/// Способы оплаты, подключенные на терминале.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct GetTerminalPayMethodsRes {
+    /// Успешность прохождения запроса.
+    pub success: bool,
+    /// Код ошибки. `0` — если ошибки не произошло.
+    pub error_code: String,
+    /// Краткое описание ошибки.
+    pub message: Option<String>,
+    /// Подробное описание ошибки.
+    pub details: Option<String>,
+    /// Настройки терминала.
+    pub terminal_info: Option<TerminalInfo>,
+}
+
+impl GetTerminalPayMethodsRes {
+    /// RU: Способ оплаты подключен на терминале. EN: Whether the pay method is enabled.
+    pub fn supports(&self, method: &PayMethod) -> bool {
+        self.terminal_info
+            .as_ref()
+            .is_some_and(|info| info.paymethods.iter().any(|m| &m.pay_method == method))
+    }
+}
+
+/// Настройки терминала.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct TerminalInfo {
+    /// Подключенные способы оплаты.
+    #[serde(default)]
+    pub paymethods: Vec<TerminalPayMethod>,
+    /// На форме доступна привязка карты.
+    #[serde(default)]
+    pub add_card_scheme: bool,
+    /// Для запросов нужна подпись `Token`.
+    #[serde(default)]
+    pub token_required: bool,
+    /// Для `Init` нужна подпись `Token`.
+    #[serde(default)]
+    pub init_token_required: bool,
+}
+
+/// Способ оплаты на терминале.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct TerminalPayMethod {
+    /// Способ оплаты.
+    pub pay_method: PayMethod,
+    /// Параметры способа оплаты, например версия T‑Pay.
+    #[serde(default)]
+    pub params: Map<String, Value>,
+}
+
+impl Client {
+    /// RU: Инициировать платеж (`/v2/Init`).  
+    /// EN: Create a payment session (`/v2/Init`).
+    pub async fn init(&self, req: &InitPaymentReq) -> Result<InitPaymentRes, Error> {
+        self.post("Init", req).await
+    }
+
+    /// RU: Получить статус платежа (`/v2/GetState`).  
+    /// EN: Get the current payment status (`/v2/GetState`).
+    pub async fn get_state(&self, payment_id: &PaymentId) -> Result<GetStateRes, Error> {
+        self.post("GetState", &GetStateReq { payment_id }).await
+    }
+
+    /// RU: Подтвердить двухстадийный платеж (`/v2/Confirm`).  
+    /// EN: Confirm a two-stage payment (`/v2/Confirm`).
+    pub async fn confirm(&self, req: &ConfirmReq) -> Result<ConfirmRes, Error> {
+        self.post("Confirm", req).await
+    }
+
+    /// RU: Отменить платеж (`/v2/Cancel`): отмена авторизации или возврат.  
+    /// EN: Cancel a payment (`/v2/Cancel`): reversal or refund.
+    pub async fn cancel(&self, req: &CancelReq) -> Result<CancelRes, Error> {
+        self.post("Cancel", req).await
+    }
+
+    /// RU: Получить все платежи по заказу (`/v2/CheckOrder`).  
+    /// EN: List every payment attempt for an order (`/v2/CheckOrder`).
+    pub async fn check_order(&self, order_id: &OrderId) -> Result<CheckOrderRes, Error> {
+        self.post("CheckOrder", &CheckOrderReq { order_id }).await
+    }
+
+    /// RU: Провести автоплатеж по сохраненной карте (`/v2/Charge`). Отказ приходит как
+    /// [Error::Api], причину дает [Error::charge_error].  
+    /// EN: Charge a saved card for a freshly initialized payment (`/v2/Charge`). A decline is
+    /// returned as [Error::Api]; [Error::charge_error] classifies it.
+    pub async fn charge(&self, req: &ChargeReq) -> Result<ChargeRes, Error> {
+        self.post("Charge", req).await
+    }
+
+    /// RU: Оплатить платеж данными карты с формы мерчанта (`/v2/FinishAuthorize`).  
+    /// EN: Pay with card data collected on the merchant form (`/v2/FinishAuthorize`).
+    pub async fn finish_authorize(
+        &self,
+        req: &FinishAuthorizeReq,
+    ) -> Result<FinishAuthorizeRes, Error> {
+        self.post("FinishAuthorize", req).await
+    }
+
+    /// RU: Получить способы оплаты, подключенные на терминале (`/v2/GetTerminalPayMethods`).
+    /// EN: List the pay methods enabled on the terminal.
+    pub async fn get_terminal_pay_methods(
+        &self,
+        pay_source: PaySource,
+    ) -> Result<GetTerminalPayMethodsRes, Error> {
+        self.get(
+            Service::Acquiring,
+            "GetTerminalPayMethods",
+            &GetTerminalPayMethodsReq {
+                terminal_key: self.terminal_key(),
+                pay_source,
+            },
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
 mod test {
+    use super::*;
+    use crate::{
+        ItemFFD105, ItemName, Password, Quantity, ReceiptFFD105, Tax, Taxation, Token,
+        client::deserialize_response,
+    };
+    use serde_json::Value;
 
     #[test]
     fn parse_request() {
         let json = r#"
         {"TerminalKey":"TBankTest","Amount":140000,"OrderId":"21090","Description":"Подарочная карта на 1000 рублей","Token":"68711168852240a2f34b6a8b19d2cfbd296c7d2a6dff8b23eda6278985959346","DATA":{"Phone":"+71234567890","Email":"a@test.com"},"Receipt":{"Email":"a@test.ru","Phone":"+79031234567","Taxation":"osn","Items":[{"Name":"Наименование товара 1","Price":10000,"Quantity":1,"Amount":10000,"Tax":"vat10","Ean13":"303130323930303030630333435"},{"Name":"Наименование товара 2","Price":20000,"Quantity":2,"Amount":40000,"Tax":"vat20"},{"Name":"Наименование товара 3","Price":30000,"Quantity":3,"Amount":90000,"Tax":"vat10"}]}}
         "#;
+        let Value::Object(mut params) = serde_json::from_str(json).unwrap() else {
+            panic!("request is not an object");
+        };
+        let password = Password::new("password");
+        let full = Token::generate(&params, &password);
+
+        let _ = params.remove("DATA");
+        let _ = params.remove("Receipt");
+
+        assert_eq!(full, Token::generate(&params, &password));
     }
 
     #[test]
     fn parse_response() {
         let json = r#"{"Success":true,"ErrorCode":"0","TerminalKey":"TBankTest","Status":"NEW","PaymentId":"3093639567","OrderId":"21090","Amount":140000,"PaymentURL":"https://pay.tbank.ru/new/fU1ppgqa"}"#;
+        let res: InitPaymentRes = serde_json::from_str(json).unwrap();
+
+        assert!(res.success());
+        assert_eq!(res.status(), &PaymentStatus::New);
+        assert_eq!(res.error_code(), "0");
+        assert_eq!(
+            res.payment_url().map(Url::as_str),
+            Some("https://pay.tbank.ru/new/fU1ppgqa")
+        );
+    }
+
+    #[test]
+    fn validated_strings() {
+        assert!(OrderId::try_from("21090").is_ok());
+        assert!(OrderId::try_from("").is_err());
+        assert!(matches!(
+            CustomerKey::try_from("k".repeat(37).as_str()),
+            Err(Error::Validation {
+                field: "CustomerKey",
+                ..
+            })
+        ));
+        assert!(Description::try_from("д".repeat(140).as_str()).is_ok());
+        assert!(OrderId::new("x".repeat(37)).is_err());
+        assert!(Description::new("я".repeat(141)).is_err());
+        assert!(CustomerKey::new("").is_err());
+        assert!(TerminalKey::new("t".repeat(21)).is_err());
+        assert!(serde_json::from_str::<OrderId>(&format!("{:?}", "x".repeat(37))).is_err());
+        assert!(serde_json::from_str::<TerminalKey>(r#""TBankTest""#).is_ok());
+    }
+
+    #[test]
+    fn build_init_request() {
+        let amount = Amount::new(NonZeroU32::new(140000).unwrap());
+        let due = Utc::now() + TimeDelta::days(1);
+        let req = InitPaymentReq::builder(amount, OrderId::try_from("21090").unwrap())
+            .description(Description::try_from("Подарочная карта").unwrap())
+            .customer_key(CustomerKey::try_from("user-1").unwrap())
+            .success_url(Url::parse("https://shop.example/ok").unwrap())
+            .redirect_due_date(RedirectDueDate::new(due))
+            .data(
+                Data::new()
+                    .operation_initiator_type(OperationInitiatorType::CitCof)
+                    .device(Device::Desktop),
+            )
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&req).unwrap(),
+            serde_json::json!({
+                "Amount": 140000,
+                "OrderId": "21090",
+                "Description": "Подарочная карта",
+                "CustomerKey": "user-1",
+                "SuccessURL": "https://shop.example/ok",
+                "RedirectDueDate": due.format("%Y-%m-%dT%H:%M:%S+00:00").to_string(),
+                "DATA": {"OperationInitiatorType": "2", "Device": "Desktop"}
+            })
+        );
+
+        let minimal = InitPaymentReq::builder(amount, OrderId::try_from("21090").unwrap())
+            .build()
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(&minimal).unwrap(),
+            serde_json::json!({"Amount": 140000, "OrderId": "21090"})
+        );
+    }
+
+    #[test]
+    fn build_checks_limits() {
+        let field = |res: Result<InitPaymentReq, Error>| match res {
+            Err(Error::Validation { field, .. }) => field,
+            other => panic!("expected validation error, got {other:?}"),
+        };
+        let amount = Amount::new(NonZeroU32::new(140000).unwrap());
+        let order_id = || OrderId::try_from("21090").unwrap();
+        let now = Utc::now();
+
+        assert!(
+            InitPaymentReq::builder(amount, order_id())
+                .description(Description::new("я".repeat(140)).unwrap())
+                .build()
+                .is_ok()
+        );
+        for due in [now + TimeDelta::seconds(30), now + TimeDelta::days(91)] {
+            assert_eq!(
+                field(
+                    InitPaymentReq::builder(amount, order_id())
+                        .redirect_due_date(RedirectDueDate::new(due))
+                        .build()
+                ),
+                "RedirectDueDate"
+            );
+        }
+        let data = (0..21).fold(Data::new(), |data, i| data.insert(format!("k{i}"), "v"));
+        assert_eq!(
+            field(
+                InitPaymentReq::builder(amount, order_id())
+                    .data(data)
+                    .build()
+            ),
+            "DATA"
+        );
+        assert_eq!(
+            field(
+                InitPaymentReq::builder(amount, order_id())
+                    .data(Data::new().insert("k", "v".repeat(101)))
+                    .build()
+            ),
+            "DATA"
+        );
+        let sbp = |kopecks| {
+            InitPaymentReq::builder(Amount::new(NonZeroU32::new(kopecks).unwrap()), order_id())
+                .data(Data::new().qr(true))
+                .build()
+        };
+        assert_eq!(field(sbp(999)), "Amount");
+        assert!(sbp(1000).is_ok());
+
+        let receipt = |kopecks| {
+            let item_amount = Amount::new(NonZeroU32::new(kopecks).unwrap());
+            ReceiptFFD105::builder(Taxation::Osn)
+                .email(Email::try_from("a@test.ru").unwrap())
+                .item(ItemFFD105::new(
+                    ItemName::try_from("Кофе").unwrap(),
+                    item_amount,
+                    Quantity::new(1.0).unwrap(),
+                    item_amount,
+                    Tax::None,
+                ))
+                .build()
+                .unwrap()
+        };
+        let with_receipt = |kopecks| {
+            InitPaymentReq::builder(amount, order_id())
+                .receipt(receipt(kopecks))
+                .build()
+        };
+        assert!(with_receipt(140000).is_ok());
+        assert_eq!(field(with_receipt(139900)), "Receipt");
+    }
+
+    #[test]
+    fn serialize_partial_confirm() {
+        let amount = Amount::new(NonZeroU32::new(5000).unwrap());
+        let req = ConfirmReq::new(PaymentId::new("700000085101"))
+            .amount(amount)
+            .shops(vec![Shop::new("700017436", amount).fee("100")]);
+
+        assert_eq!(
+            serde_json::to_value(&req).unwrap(),
+            serde_json::json!({
+                "PaymentId": "700000085101",
+                "Amount": 5000,
+                "Shops": [{"ShopCode": "700017436", "Amount": 5000, "Fee": "100"}]
+            })
+        );
+    }
+
+    #[test]
+    fn parse_confirm_response() {
+        let json = r#"{"TerminalKey":"TBankTest","OrderId":"21050","Success":true,"Status":"CONFIRMED","PaymentId":"13660","ErrorCode":"0","Message":"Неверные параметры","Details":"Подробности"}"#;
+        let res: ConfirmRes = serde_json::from_str(json).unwrap();
+
+        assert_eq!(res.status, PaymentStatus::Confirmed);
+    }
+
+    #[test]
+    fn serialize_installment_refund() {
+        let req = CancelReq::new(PaymentId::new("700000085101"))
+            .route(Route::Tcb)
+            .source(Source::Installment)
+            .qr_member_id(QrMemberId::new("100000000004"));
+
+        assert_eq!(
+            serde_json::to_value(&req).unwrap(),
+            serde_json::json!({
+                "PaymentId": "700000085101",
+                "QrMemberId": "100000000004",
+                "Route": "TCB",
+                "Source": "installment"
+            })
+        );
+    }
+
+    #[test]
+    fn cancel_outcome() {
+        let parse = |status: &str, original: u32, new: u32| -> CancelRes {
+            serde_json::from_value(serde_json::json!({
+                "TerminalKey": "TBankTest",
+                "OrderId": "21057",
+                "Success": true,
+                "Status": status,
+                "OriginalAmount": original,
+                "NewAmount": new,
+                "PaymentId": "2304882",
+                "ErrorCode": "0"
+            }))
+            .unwrap()
+        };
+
+        assert_eq!(
+            parse("REVERSED", 13000, 0).outcome(),
+            CancelOutcome::Reversal
+        );
+        assert_eq!(
+            parse("PARTIAL_REVERSED", 13000, 5000).outcome(),
+            CancelOutcome::PartialReversal
+        );
+        assert_eq!(parse("REFUNDED", 13000, 0).outcome(), CancelOutcome::Refund);
+        assert_eq!(
+            parse("PARTIAL_REFUNDED", 13000, 5000).outcome(),
+            CancelOutcome::PartialRefund
+        );
+        assert_eq!(parse("CANCELED", 13000, 0).outcome(), CancelOutcome::Other);
+        assert_eq!(
+            parse("PARTIAL_REFUNDED", 13000, 5000).cancelled_amount(),
+            8000
+        );
+    }
+
+    #[test]
+    fn parse_check_order_response() {
+        let json = r#"{"TerminalKey":"TBankTest","OrderId":"21057","Success":true,"ErrorCode":"0","Message":"OK","Details":"None","Payments":[{"PaymentId":"124671934","Amount":100000,"Status":"REJECTED","RRN":"1234567","Success":false,"ErrorCode":"1051","Message":"Недостаточно средств на карте"},{"PaymentId":"124671935","Amount":100000,"Status":"CONFIRMED","RRN":"1234568","Success":true,"ErrorCode":"0"}]}"#;
+        let res: CheckOrderRes = serde_json::from_str(json).unwrap();
+
+        assert_eq!(res.payments.len(), 2);
+        assert_eq!(res.payments[0].status, PaymentStatus::Rejected);
+        assert_eq!(
+            res.successful_payment().map(|p| p.payment_id.as_str()),
+            Some("124671935")
+        );
+    }
+
+    #[test]
+    fn serialize_charge() {
+        let req = ChargeReq::new(PaymentId::new("700000085101"), RebillId::new("145919"))
+            .send_email(Email::new("a@test.ru").unwrap());
+
+        assert_eq!(
+            serde_json::to_value(&req).unwrap(),
+            serde_json::json!({
+                "PaymentId": "700000085101",
+                "RebillId": "145919",
+                "SendEmail": true,
+                "InfoEmail": "a@test.ru"
+            })
+        );
+    }
+
+    #[test]
+    fn charge_error_codes() {
+        let json = r#"{"TerminalKey":"TBankTest","Amount":100000,"OrderId":"21050","Success":false,"Status":"REJECTED","PaymentId":"13660","ErrorCode":"1051","Message":"Недостаточно средств на карте"}"#;
+        let err = deserialize_response::<ChargeRes>(json.to_string()).unwrap_err();
+
+        let error = err.charge_error().unwrap();
+        assert_eq!(error, ChargeError::InsufficientFunds);
+        assert!(error.is_retryable());
+        assert!(!ChargeError::from("1054").is_retryable());
+        assert_eq!(
+            ChargeError::from("9999"),
+            ChargeError::Other("9999".to_string())
+        );
+
+        let json = r#"{"Success":false,"ErrorCode":"1051","Message":"Недостаточно средств на карте","Details":"Списание по RebillId отклонено"}"#;
+        let err = deserialize_response::<ChargeRes>(json.to_string()).unwrap_err();
+
+        assert_eq!(err.charge_error(), Some(ChargeError::InsufficientFunds));
+        assert!(Error::Timeout.charge_error().is_none());
+
+        let json = r#"{"TerminalKey":"TBankTest","Amount":100000,"OrderId":"21050","Success":true,"Status":"CONFIRMED","PaymentId":"13660","ErrorCode":"0"}"#;
+        let res = deserialize_response::<ChargeRes>(json.to_string()).unwrap();
+
+        assert_eq!(res.status, PaymentStatus::Confirmed);
+    }
+
+    #[test]
+    fn parse_get_state_response() {
+        let json = r#"{"Success":true,"ErrorCode":"0","Message":"OK","TerminalKey":"TBankTest","Status":"CONFIRMED","PaymentId":"13660","OrderId":"21057","Amount":100000,"Params":[{"Key":"Route","Value":"TCB"},{"Key":"Source","Value":"Installment"}]}"#;
+        let res: GetStateRes = serde_json::from_str(json).unwrap();
+
+        assert_eq!(res.status, PaymentStatus::Confirmed);
+        assert_eq!(res.payment_id.as_str(), "13660");
+        assert_eq!(res.params[1].value, "Installment");
+    }
+
+    #[test]
+    fn parse_finish_authorize_3ds_response() {
+        let json = r#"{"TerminalKey":"TBankTest","Amount":100000,"OrderId":"21050","Success":true,"Status":"3DS_CHECKING","PaymentId":"13660","ErrorCode":"0","ACSUrl":"https://secure.tcsbank.ru/acs/auth/start.do","MD":"ACQT-563587291","PaReq":"eJxVUtFqwjAUfR/"}"#;
+        let res: FinishAuthorizeRes = serde_json::from_str(json).unwrap();
+
+        assert_eq!(res.status, PaymentStatus::ThreeDsChecking);
+        assert_eq!(res.md.as_deref(), Some("ACQT-563587291"));
+        assert!(res.acs_url.is_some());
+    }
+
+    #[test]
+    fn parse_terminal_pay_methods() {
+        let json = r#"{"Success":true,"ErrorCode":"0","TerminalInfo":{"Paymethods":[{"PayMethod":"TinkoffPay","Params":{"Version":"2.0"}},{"PayMethod":"MirPay"},{"PayMethod":"Unicorn"}],"AddCardScheme":true,"TokenRequired":true,"InitTokenRequired":false}}"#;
+        let res: GetTerminalPayMethodsRes = serde_json::from_str(json).unwrap();
+
+        assert!(res.supports(&PayMethod::MirPay));
+        assert!(!res.supports(&PayMethod::SberPay));
+        let info = res.terminal_info.unwrap();
+        assert_eq!(info.paymethods[0].params["Version"], "2.0");
+        assert_eq!(
+            info.paymethods[2].pay_method,
+            PayMethod::Unknown("Unicorn".to_string())
+        );
     }
 }
@@ -1,28 +1,101 @@
-use crate::Amount;
-use chrono::{DateTime, Utc};
+use crate::validate::{self, validated_string};
+use crate::{Amount, Error};
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 use std::num::NonZeroU16;
 
-/// Позиция чека с информацией о товарах.
+/// Позиция чека ФФД 1.05 с информацией о товарах.
+///
+/// `PaymentMethod` и `PaymentObject` по умолчанию — full_payment и commodity.
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "PascalCase")]
-struct ItemFFD105 {
+pub struct ItemFFD105 {
     name: ItemName,
     price: ItemPrice,
     quantity: ItemQuantity,
     amount: ItemAmount,
+    #[serde(default)]
     payment_method: PaymentMethod,
+    #[serde(default)]
     payment_object: PaymentObjectFF105,
     tax: Tax,
-    ean_13: Ean13,
-    agent_data: AgentData,
-    supplier_info: SupplierInfo,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ean_13: Option<Ean13>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    agent_data: Option<AgentData>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    supplier_info: Option<SupplierInfo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shop_code: Option<ShopCode>,
+}
+
+impl ItemFFD105 {
+    /// RU: Позиция с обязательными тегами: наименование, цена, количество, сумма и ставка НДС.
+    /// EN: Item with the mandatory tags.
+    pub fn new(
+        name: ItemName,
+        price: Amount,
+        quantity: Quantity,
+        amount: Amount,
+        tax: Tax,
+    ) -> Self {
+        Self {
+            name,
+            price: ItemPrice(price),
+            quantity: ItemQuantity(quantity),
+            amount: ItemAmount(amount),
+            payment_method: PaymentMethod::default(),
+            payment_object: PaymentObjectFF105::default(),
+            tax,
+            ean_13: None,
+            agent_data: None,
+            supplier_info: None,
+            shop_code: None,
+        }
+    }
+
+    /// RU: Признак способа расчета. EN: Payment method.
+    pub fn payment_method(mut self, payment_method: PaymentMethod) -> Self {
+        self.payment_method = payment_method;
+        self
+    }
+
+    /// RU: Признак предмета расчета. EN: Payment object.
+    pub fn payment_object(mut self, payment_object: PaymentObjectFF105) -> Self {
+        self.payment_object = payment_object;
+        self
+    }
+
+    /// RU: Штрихкод. EN: Barcode.
+    pub fn ean13(mut self, ean13: Ean13) -> Self {
+        self.ean_13 = Some(ean13);
+        self
+    }
+
+    /// RU: Данные агента и поставщика. EN: Agent and supplier data.
+    pub fn agent(mut self, agent_data: AgentData, supplier_info: SupplierInfo) -> Self {
+        self.agent_data = Some(agent_data);
+        self.supplier_info = Some(supplier_info);
+        self
+    }
+
+    /// RU: Код магазина. EN: Shop code.
+    pub fn shop_code(mut self, shop_code: ShopCode) -> Self {
+        self.shop_code = Some(shop_code);
+        self
+    }
+
+    fn validate(&self, index: usize) -> Result<(), Error> {
+        validate_agent(index, self.agent_data.as_ref(), self.supplier_info.as_ref())
+    }
 }
 
-/// Позиция чека с информацией о товарах.
+/// Позиция чека ФФД 1.2 с информацией о товарах.
+///
+/// Параметры для маркируемых товаров не обязательны для товаров без маркировки.
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "PascalCase")]
-struct ItemFFD12 {
+pub struct ItemFFD12 {
     name: ItemName,
     price: ItemPrice,
     quantity: ItemQuantity,
@@ -30,31 +103,320 @@ struct ItemFFD12 {
     payment_method: PaymentMethod,
     payment_object: PaymentObjectFF12,
     tax: Tax,
-    agent_data: AgentData,
-    supplier_info: SupplierInfo,
-    user_data: UserData,
-    excise: Excise,
-    country_code: CountryCode,
-    declaration_number: DeclarationNumber,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    agent_data: Option<AgentData>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    supplier_info: Option<SupplierInfo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    user_data: Option<UserData>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    excise: Option<Excise>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    country_code: Option<CountryCode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    declaration_number: Option<DeclarationNumber>,
     measurement_unit: MeasurementUnit,
-    mark_processing_mode: MarkProcessingMode,
-    mark_code: Vec<MarkCode>,
-    mark_quantity: MarkQuantity,
-    sectoral_item_props: SectoralCheckProps,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mark_processing_mode: Option<MarkProcessingMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mark_code: Option<MarkCode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mark_quantity: Option<MarkQuantity>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sectoral_item_props: Option<SectoralItemProps>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shop_code: Option<ShopCode>,
+}
+
+impl ItemFFD12 {
+    /// RU: Позиция с обязательными тегами. `PaymentMethod` и `PaymentObject` по умолчанию —
+    /// full_payment и commodity, их можно изменить сеттерами.
+    /// EN: Item with the mandatory tags; payment method and object default to
+    /// full_payment and commodity.
+    pub fn new(
+        name: ItemName,
+        price: Amount,
+        quantity: Quantity,
+        amount: Amount,
+        tax: Tax,
+        measurement_unit: MeasurementUnit,
+    ) -> Self {
+        Self {
+            name,
+            price: ItemPrice(price),
+            quantity: ItemQuantity(quantity),
+            amount: ItemAmount(amount),
+            payment_method: PaymentMethod::default(),
+            payment_object: PaymentObjectFF12::default(),
+            tax,
+            agent_data: None,
+            supplier_info: None,
+            user_data: None,
+            excise: None,
+            country_code: None,
+            declaration_number: None,
+            measurement_unit,
+            mark_processing_mode: None,
+            mark_code: None,
+            mark_quantity: None,
+            sectoral_item_props: None,
+            shop_code: None,
+        }
+    }
+
+    /// RU: Признак способа расчета. EN: Payment method.
+    pub fn payment_method(mut self, payment_method: PaymentMethod) -> Self {
+        self.payment_method = payment_method;
+        self
+    }
+
+    /// RU: Признак предмета расчета. EN: Payment object.
+    pub fn payment_object(mut self, payment_object: PaymentObjectFF12) -> Self {
+        self.payment_object = payment_object;
+        self
+    }
+
+    /// RU: Данные агента и поставщика. EN: Agent and supplier data.
+    pub fn agent(mut self, agent_data: AgentData, supplier_info: SupplierInfo) -> Self {
+        self.agent_data = Some(agent_data);
+        self.supplier_info = Some(supplier_info);
+        self
+    }
+
+    /// RU: Дополнительный реквизит предмета расчета. EN: Additional item attribute.
+    pub fn user_data(mut self, user_data: impl Into<String>) -> Self {
+        self.user_data = Some(UserData(user_data.into()));
+        self
+    }
+
+    /// RU: Сумма акциза в рублях, например `12.50`. EN: Excise amount in rubles.
+    pub fn excise(mut self, excise: impl Into<String>) -> Self {
+        self.excise = Some(Excise(excise.into()));
+        self
+    }
+
+    /// RU: Код страны происхождения и номер таможенной декларации.
+    /// EN: Country of origin code and customs declaration number.
+    pub fn import(
+        mut self,
+        country_code: impl Into<String>,
+        declaration_number: impl Into<String>,
+    ) -> Self {
+        self.country_code = Some(CountryCode(country_code.into()));
+        self.declaration_number = Some(DeclarationNumber(declaration_number.into()));
+        self
+    }
+
+    /// RU: Код маркировки. Режим обработки кода маркировки проставляется автоматически.
+    /// EN: Marking code; the processing mode is filled in automatically.
+    pub fn mark_code(mut self, mark_code: MarkCode) -> Self {
+        self.mark_processing_mode = Some(MarkProcessingMode("0".to_string()));
+        self.mark_code = Some(mark_code);
+        self
+    }
+
+    /// RU: Дробное количество маркированного товара; только для `MeasurementUnit` = шт.
+    /// EN: Fractional marked goods quantity, only for items measured in pieces.
+    pub fn mark_quantity(mut self, mark_quantity: MarkQuantity) -> Self {
+        self.mark_quantity = Some(mark_quantity);
+        self
+    }
+
+    /// RU: Отраслевые реквизиты предмета расчета. EN: Sectoral item attributes.
+    pub fn sectoral_item_props(mut self, props: Vec<SectoralItemProp>) -> Self {
+        self.sectoral_item_props = Some(SectoralItemProps(props));
+        self
+    }
+
+    /// RU: Код магазина. EN: Shop code.
+    pub fn shop_code(mut self, shop_code: ShopCode) -> Self {
+        self.shop_code = Some(shop_code);
+        self
+    }
+
+    fn validate(&self, index: usize) -> Result<(), Error> {
+        validate_agent(index, self.agent_data.as_ref(), self.supplier_info.as_ref())?;
+        if let Some(CountryCode(code)) = &self.country_code {
+            validate::digits("CountryCode", code, &[3])?;
+        }
+        if let Some(DeclarationNumber(number)) = &self.declaration_number {
+            validate::length("DeclarationNumber", number, 1, 32)?;
+        }
+        if let Some(Excise(excise)) = &self.excise {
+            Excise::check(excise)?;
+        }
+
+        let marked = self.payment_object.is_marked();
+        if marked && self.mark_code.is_none() {
+            return Err(Error::Validation {
+                field: "MarkCode",
+                reason: format!("Items[{index}]: required for marked goods"),
+            });
+        }
+        if self.mark_quantity.is_some()
+            && !(marked && matches!(self.measurement_unit, MeasurementUnit::Piece))
+        {
+            return Err(Error::Validation {
+                field: "MarkQuantity",
+                reason: format!("Items[{index}]: allowed only for marked goods measured in pieces"),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Проверка данных агента и поставщика позиции `index`.
+fn validate_agent(
+    index: usize,
+    agent_data: Option<&AgentData>,
+    supplier_info: Option<&SupplierInfo>,
+) -> Result<(), Error> {
+    let Some(agent_data) = agent_data else {
+        return Ok(());
+    };
+    let with_index = |err: Error| match err {
+        Error::Validation { field, reason } => Error::Validation {
+            field,
+            reason: format!("Items[{index}]: {reason}"),
+        },
+        other => other,
+    };
+
+    agent_data.validate().map_err(with_index)?;
+    supplier_info
+        .ok_or_else(|| Error::Validation {
+            field: "SupplierInfo",
+            reason: "required when AgentData is passed".to_string(),
+        })
+        .and_then(SupplierInfo::validate)
+        .map_err(with_index)
 }
 
 /// Данные агента. Параметр обязательный, если используется агентская схема.
+///
+/// Какие теги обязательны, зависит от [AgentSign]; проверяется при сборке чека.
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "PascalCase")]
-struct AgentData {
+pub struct AgentData {
     agent_sign: AgentSign,
-    operation_name: OperationName,
-    phones: AgentPhones,
-    receiver_phones: ReceiverPhones,
-    transfer_phones: TransferPhones,
-    operator_name: OperatorName,
-    operator_address: OperatorAddress,
-    perator_inn: OperatorInn,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    operation_name: Option<OperationName>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    phones: Option<AgentPhones>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    receiver_phones: Option<ReceiverPhones>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    transfer_phones: Option<TransferPhones>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    operator_name: Option<OperatorName>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    operator_address: Option<OperatorAddress>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    operator_inn: Option<OperatorInn>,
+}
+
+impl AgentData {
+    /// RU: Данные агента с признаком агента. EN: Agent data with the agent sign.
+    pub fn new(agent_sign: AgentSign) -> Self {
+        Self {
+            agent_sign,
+            operation_name: None,
+            phones: None,
+            receiver_phones: None,
+            transfer_phones: None,
+            operator_name: None,
+            operator_address: None,
+            operator_inn: None,
+        }
+    }
+
+    /// RU: Наименование операции. EN: Operation name.
+    pub fn operation_name(mut self, name: impl Into<String>) -> Self {
+        self.operation_name = Some(OperationName(name.into()));
+        self
+    }
+
+    /// RU: Телефоны платежного агента. EN: Paying agent phones.
+    pub fn phones(mut self, phones: Vec<Phone>) -> Self {
+        self.phones = Some(AgentPhones(phones));
+        self
+    }
+
+    /// RU: Телефоны оператора по приему платежей (тег 1074). EN: Payment receiver phones.
+    pub fn receiver_phones(mut self, phones: Vec<Phone>) -> Self {
+        self.receiver_phones = Some(ReceiverPhones(phones));
+        self
+    }
+
+    /// RU: Телефоны оператора перевода (тег 1075). EN: Transfer operator phones.
+    pub fn transfer_phones(mut self, phones: Vec<Phone>) -> Self {
+        self.transfer_phones = Some(TransferPhones(phones));
+        self
+    }
+
+    /// RU: Наименование, адрес и ИНН оператора перевода.
+    /// EN: Transfer operator name, address and INN.
+    pub fn operator(
+        mut self,
+        name: impl Into<String>,
+        address: impl Into<String>,
+        inn: Inn,
+    ) -> Self {
+        self.operator_name = Some(OperatorName(name.into()));
+        self.operator_address = Some(OperatorAddress(address.into()));
+        self.operator_inn = Some(OperatorInn(inn));
+        self
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        let required = |field: &'static str, present: bool| {
+            if present {
+                Ok(())
+            } else {
+                Err(Error::Validation {
+                    field,
+                    reason: format!("required for AgentSign {:?}", self.agent_sign),
+                })
+            }
+        };
+
+        let bank = matches!(
+            self.agent_sign,
+            AgentSign::BankPayingAgent | AgentSign::BankPayingSubagent
+        );
+        let paying = matches!(
+            self.agent_sign,
+            AgentSign::PayingAgent | AgentSign::PayingSubagent
+        );
+
+        if bank {
+            required("OperationName", self.operation_name.is_some())?;
+            required("OperatorName", self.operator_name.is_some())?;
+            required("OperatorAddress", self.operator_address.is_some())?;
+            required("OperatorInn", self.operator_inn.is_some())?;
+        }
+        if bank || paying {
+            required("Phones", self.phones.is_some())?;
+        }
+        if paying {
+            required("ReceiverPhones", self.receiver_phones.is_some())?;
+            required("TransferPhones", self.transfer_phones.is_some())?;
+        }
+
+        if let Some(OperationName(name)) = &self.operation_name {
+            validate::length("OperationName", name, 1, 24)?;
+        }
+        if let Some(OperatorName(name)) = &self.operator_name {
+            validate::length("OperatorName", name, 1, 64)?;
+        }
+        if let Some(OperatorAddress(address)) = &self.operator_address {
+            validate::length("OperatorAddress", address, 1, 243)?;
+        }
+        if let Some(OperatorInn(inn)) = &self.operator_inn {
+            inn.require_kind("OperatorInn", InnKind::LegalEntity)?;
+        }
+        Ok(())
+    }
 }
 
 /// Requirements: <= 128 characters
@@ -62,9 +424,17 @@ struct AgentData {
 /// Тег ФФД: 1030
 ///
 /// Наименование товара.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Debug)]
 #[serde(transparent)]
-struct ItemName(String);
+pub struct ItemName(String);
+
+validated_string!(ItemName);
+
+impl ItemName {
+    fn check(value: &str) -> Result<(), Error> {
+        validate::length("Name", value, 1, 128)
+    }
+}
 
 /// Тег ФФД: 1078
 /// Цена в копейках.
@@ -93,10 +463,6 @@ struct ItemQuantity(Quantity);
 #[serde(transparent)]
 struct ItemAmount(Amount);
 
-#[derive(Serialize, Deserialize, Debug)]
-#[serde(transparent)]
-struct ItemTax(Tax);
-
 /// Тег ФФД: 1073
 ///
 /// Телефоны платежного агента в формате +{Ц}.
@@ -134,10 +500,21 @@ struct TransferPhones(Vec<Phone>);
 /// Тег ФФД: 1016
 ///
 /// ИНН оператора перевода. Параметр обязательный, если AgentSign передан в /// значениях bank_paying_agent или bank_paying_subagent.
-#[derive(Serialize, Deserialize, Debug)]
+///
+/// Оператор перевода — кредитная организация, поэтому ИНН должен быть ИНН юрлица.
+#[derive(Serialize, Debug)]
 #[serde(transparent)]
 struct OperatorInn(Inn);
 
+impl<'de> Deserialize<'de> for OperatorInn {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let inn = Inn::deserialize(deserializer)?;
+        inn.require_kind("OperatorInn", InnKind::LegalEntity)
+            .map_err(serde::de::Error::custom)?;
+        Ok(Self(inn))
+    }
+}
+
 /// Requirements: [bank_paying_agent, bank_paying_subagent, paying_agent, paying_subagent, attorney, commission_agent, another]
 ///
 /// Тег ФФД: 1222
@@ -151,14 +528,22 @@ struct OperatorInn(Inn);
 /// - attorney — поверенный;
 /// - commission_agent — комиссионер;
 /// - another — другой тип агента.
-#[derive(Serialize, Deserialize, Debug)]
-enum AgentSign {
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AgentSign {
+    /// Банковский платежный агент.
     BankPayingAgent,
+    /// Банковский платежный субагент.
     BankPayingSubagent,
+    /// Платежный агент.
     PayingAgent,
+    /// Платежный субагент.
     PayingSubagent,
+    /// Поверенный.
     Attorney,
-    CommisionAgent,
+    /// Комиссионер.
+    CommissionAgent,
+    /// Другой тип агента.
     Another,
 }
 
@@ -173,10 +558,38 @@ enum AgentSign {
 #[serde(transparent)]
 struct OperationName(String);
 
+/// Requirements: +{Ц}, 1–15 digits
+///
 /// Телефон в формате +{Ц}.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Debug)]
 #[serde(transparent)]
-struct Phone(String);
+pub struct Phone(String);
+
+validated_string!(Phone);
+
+impl Phone {
+    /// RU: Телефон из строки. EN: Phone number from a string.
+    pub fn new(phone: impl Into<String>) -> Result<Self, Error> {
+        let phone = phone.into();
+        Self::check(&phone)?;
+        Ok(Self(phone))
+    }
+
+    fn check(value: &str) -> Result<(), Error> {
+        match value.strip_prefix('+') {
+            Some(digits)
+                if (1..=15).contains(&digits.len())
+                    && digits.bytes().all(|b| b.is_ascii_digit()) =>
+            {
+                Ok(())
+            }
+            _ => Err(Error::Validation {
+                field: "Phone",
+                reason: format!("expected +{{digits}}, got {value:?}"),
+            }),
+        }
+    }
+}
 
 /// Requirements: <= 64 characters
 ///
@@ -249,78 +662,103 @@ struct CountryCode(String);
 /// Параметр обязательный, если версия ФФД онлайн-кассы — 1.2.
 #[derive(Debug, Serialize, Deserialize)]
 pub enum MeasurementUnit {
+    /// Штука.
     #[serde(rename = "шт")]
     Piece,
 
+    /// Грамм.
     #[serde(rename = "г")]
     Gram,
 
+    /// Килограмм.
     #[serde(rename = "кг")]
     Kilogram,
 
+    /// Тонна.
     #[serde(rename = "т")]
     Ton,
 
+    /// Сантиметр.
     #[serde(rename = "см")]
     Centimeter,
 
+    /// Дециметр.
     #[serde(rename = "дм")]
     Decimeter,
 
+    /// Метр.
     #[serde(rename = "м")]
     Meter,
 
+    /// Квадратный сантиметр.
     #[serde(rename = "см2")]
     SquareCentimeter,
 
+    /// Квадратный дециметр.
     #[serde(rename = "дм2")]
     SquareDecimeter,
 
+    /// Квадратный метр.
     #[serde(rename = "м2")]
     SquareMeter,
 
+    /// Миллилитр.
     #[serde(rename = "мл")]
     Milliliter,
 
+    /// Литр.
     #[serde(rename = "л")]
     Liter,
 
+    /// Кубический метр.
     #[serde(rename = "м3")]
     CubicMeter,
 
+    /// Киловатт-час.
     #[serde(rename = "кВт*ч")]
     KilowattHour,
 
+    /// Гигакалория.
     #[serde(rename = "Гкал")]
     Gigacalorie,
 
+    /// Сутки.
     #[serde(rename = "сут")]
     Day,
 
+    /// День.
     #[serde(rename = "дн")]
     DayAlt,
 
+    /// Час.
     #[serde(rename = "ч")]
     Hour,
 
+    /// Минута.
     #[serde(rename = "мин")]
     Minute,
 
+    /// Секунда.
     #[serde(rename = "с")]
     Second,
 
+    /// Килобайт.
     #[serde(rename = "Кбайт")]
     Kilobyte,
 
+    /// Мегабайт.
     #[serde(rename = "Мбайт")]
     Megabyte,
 
+    /// Гигабайт.
     #[serde(rename = "Гбайт")]
     Gigabyte,
 
+    /// Терабайт.
     #[serde(rename = "Тбайт")]
     Terabyte,
 
+    /// Иная единица измерения.
     #[serde(rename = "-")]
     Other,
 }
@@ -342,15 +780,26 @@ struct MarkProcessingMode(String);
 ///
 /// С 01.09.2025 для чеков с маркированными товарами обязательно передается часовая зона места расчета (тег 1011). По умолчанию — Москва. Для изменения напишите на acq_help@tbank.ru.
 #[derive(Serialize, Deserialize, Debug)]
-struct MarkCode {
+#[serde(rename_all = "PascalCase")]
+pub struct MarkCode {
     mark_code_type: MarkCodeType,
-    value: Value,
+    value: MarkCodeValue,
+}
+
+impl MarkCode {
+    /// RU: Код маркировки заданного типа. EN: Marking code of the given type.
+    pub fn new(mark_code_type: MarkCodeType, value: impl Into<String>) -> Self {
+        Self {
+            mark_code_type,
+            value: MarkCodeValue(value.into()),
+        }
+    }
 }
 
 /// Код маркировки
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(transparent)]
-struct Value(String);
+struct MarkCodeValue(String);
 
 /// Тип штрихкода:
 ///
@@ -365,19 +814,30 @@ struct Value(String);
 /// - EGAIS20 — код товара в формате ЕГАИС-2.0;
 /// - EGAIS30 — код товара в формате ЕГАИС-3.0;
 /// - RAWCODE — код маркировки, как он был прочитан сканером.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
 #[serde(rename_all = "UPPERCASE")]
-enum MarkCodeType {
+pub enum MarkCodeType {
+    /// Формат не идентифицирован.
     Unknown,
+    /// EAN-8.
     Ean8,
+    /// EAN-13.
     Ean13,
+    /// ITF-14.
     Itf14,
+    /// GS1 на товаре без маркировки.
     Gs10,
+    /// GS1 на маркированном товаре.
     Gs1m,
+    /// Короткий код маркировки.
     Short,
+    /// Контрольно-идентификационный знак мехового изделия.
     Fur,
+    /// ЕГАИС-2.0.
     Egais20,
+    /// ЕГАИС-3.0.
     Egais30,
+    /// Код маркировки, как он был прочитан сканером.
     Rawcode,
 }
 
@@ -389,11 +849,28 @@ enum MarkCodeType {
 /// { "numenator": "1" "denominator" "2" }
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "PascalCase")]
-struct MarkQuantity {
+pub struct MarkQuantity {
     numerator: Numerator,
     denominator: Denominator,
 }
 
+impl MarkQuantity {
+    /// RU: Дробное количество `numerator/denominator`, числитель строго меньше знаменателя.
+    /// EN: Fractional quantity; the numerator must be less than the denominator.
+    pub fn new(numerator: u32, denominator: u32) -> Result<Self, Error> {
+        if numerator == 0 || numerator >= denominator {
+            return Err(Error::Validation {
+                field: "MarkQuantity",
+                reason: format!("expected 0 < {numerator} < {denominator}"),
+            });
+        }
+        Ok(Self {
+            numerator: Numerator(numerator),
+            denominator: Denominator(denominator),
+        })
+    }
+}
+
 ///
 /// Тег ФФД: 1293
 ///
@@ -420,13 +897,31 @@ struct SectoralItemProps(Vec<SectoralItemProp>);
 /// Отраслевой реквизит предмета расчета.
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "PascalCase")]
-struct SectoralItemProp {
+pub struct SectoralItemProp {
     federal_id: FederalId,
     date: SectoralDate,
     number: SectoralNumber,
     value: SectoralValue,
 }
 
+impl SectoralItemProp {
+    /// RU: Реквизит из идентификатора ФОИВ, даты и номера нормативного акта и значения.
+    /// EN: Sectoral attribute from the authority id, act date and number, and value.
+    pub fn new(
+        federal_id: impl Into<String>,
+        date: DateTime<Utc>,
+        number: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Self {
+        Self {
+            federal_id: FederalId(federal_id.into()),
+            date: SectoralDate(date),
+            number: SectoralNumber(number.into()),
+            value: SectoralValue(value.into()),
+        }
+    }
+}
+
 /// Тег ФФД: 1262
 ///
 /// Идентификатор ФОИВ — федеральный орган исполнительной власти.
@@ -459,25 +954,25 @@ struct SectoralValue(String);
 ///
 /// Операционный реквизит чека.
 #[derive(Serialize, Deserialize, Debug)]
-struct OperatingCheckProps;
+pub struct OperatingCheckProps;
 
 /// Тег ФФД: 1261
 ///
 /// Отраслевой реквизит чека.
 #[derive(Serialize, Deserialize, Debug)]
-struct SectoralCheckProps;
+pub struct SectoralCheckProps;
 
 /// Тег ФФД: 1084
 ///
 /// Дополнительный реквизит пользователя.
 #[derive(Serialize, Deserialize, Debug)]
-struct AddUserProp;
+pub struct AddUserProp;
 
 /// Тег ФФД: 1192
 ///
 /// Дополнительный реквизит чека (БСО).
 #[derive(Serialize, Deserialize, Debug)]
-struct AdditionalCheckProps;
+pub struct AdditionalCheckProps;
 
 /// Requirements: <= 32 characters
 ///
@@ -495,10 +990,30 @@ struct DeclarationNumber(String);
 /// целая часть — не больше 8 знаков;
 /// дробная часть — не больше 2 знаков;
 /// значение не может быть отрицательным.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Debug)]
 #[serde(transparent)]
 struct Excise(String);
 
+validated_string!(Excise);
+
+impl Excise {
+    fn check(value: &str) -> Result<(), Error> {
+        let (integer, fraction) = value.split_once('.').unwrap_or((value, "0"));
+        let digits = |part: &str, max| {
+            (1..=max).contains(&part.len()) && part.bytes().all(|b| b.is_ascii_digit())
+        };
+        if !digits(integer, 8) || !digits(fraction, 2) {
+            return Err(Error::Validation {
+                field: "Excise",
+                reason: format!(
+                    "expected a non-negative amount with at most 8 integer and 2 fractional digits, got {value:?}"
+                ),
+            });
+        }
+        Ok(())
+    }
+}
+
 /// Requirements: [full_prepayment, prepayment, advance, full_payment, partial_payment, credit, credit_payment]
 ///
 /// Default: full_payment
@@ -516,32 +1031,139 @@ struct Excise(String);
 /// - credit_payment — оплата кредита.
 ///
 /// Если значение не передано, по умолчанию в онлайн-кассу отправляется признак предмета расчета full_payment.
-#[derive(Serialize, Deserialize, Debug, Default)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
-enum PaymentMethod {
+pub enum PaymentMethod {
+    /// Предоплата 100%.
     FullPrepayment,
+    /// Предоплата.
     Prepayment,
+    /// Аванс.
     Advance,
+    /// Полный расчет.
     #[default]
     FullPayment,
+    /// Частичный расчет и кредит.
     PartialPayment,
+    /// Передача в кредит.
     Credit,
+    /// Оплата кредита.
     CreditPayment,
 }
 
-/// ИНН
-#[derive(Serialize, Deserialize, Debug)]
+/// Requirements: 10 or 12 digits with valid control digits
+///
+/// ИНН. 10 цифр — юридическое лицо, 12 цифр — физическое лицо или ИП, см. [InnKind].
+///
+/// Контрольные цифры проверяются при создании через `TryFrom` и при десериализации.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
 #[serde(transparent)]
 pub struct Inn(String);
 
+validated_string!(Inn);
+
+impl Inn {
+    /// RU: ИНН в виде строки. EN: INN as a string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// RU: Вид ИНН по количеству цифр. EN: INN kind by its length.
+    pub fn kind(&self) -> InnKind {
+        if self.0.len() == 10 {
+            InnKind::LegalEntity
+        } else {
+            InnKind::Individual
+        }
+    }
+
+    /// RU: Проверить, что ИНН нужного вида для тега `field`.
+    /// EN: Check that the INN has the kind required by the `field` tag.
+    pub fn require_kind(&self, field: &'static str, kind: InnKind) -> Result<(), Error> {
+        if self.kind() != kind {
+            return Err(Error::Validation {
+                field,
+                reason: format!("expected {kind:?} INN, got {}", self.0),
+            });
+        }
+        Ok(())
+    }
+
+    fn check(value: &str) -> Result<(), Error> {
+        validate::digits("Inn", value, &[10, 12])?;
+
+        let digits: Vec<u32> = value.bytes().map(|b| u32::from(b - b'0')).collect();
+        let control = |weights: &[u32]| {
+            weights.iter().zip(&digits).map(|(w, d)| w * d).sum::<u32>() % 11 % 10
+        };
+        let valid = match digits.len() {
+            10 => control(&[2, 4, 10, 3, 5, 9, 4, 6, 8]) == digits[9],
+            _ => {
+                control(&[7, 2, 4, 10, 3, 5, 9, 4, 6, 8]) == digits[10]
+                    && control(&[3, 7, 2, 4, 10, 3, 5, 9, 4, 6, 8]) == digits[11]
+            }
+        };
+        if !valid {
+            return Err(Error::Validation {
+                field: "Inn",
+                reason: format!("control digits do not match in {value}"),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Requirements: [10 digits, 12 digits]
+///
+/// Вид ИНН:
+///
+/// - 10 цифр — юридическое лицо;
+/// - 12 цифр — физическое лицо или индивидуальный предприниматель.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InnKind {
+    /// ИНН юридического лица, 10 цифр.
+    LegalEntity,
+    /// ИНН физического лица или ИП, 12 цифр.
+    Individual,
+}
+
+/// Данные поставщика. Параметр обязательный, если передается [AgentData].
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "PascalCase")]
-struct SupplierInfo {
+pub struct SupplierInfo {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     phones: Vec<Phone>,
     name: SupplierName,
     inn: Inn,
 }
 
+impl SupplierInfo {
+    /// RU: Поставщик с наименованием и ИНН (тег 1226). EN: Supplier with name and INN.
+    pub fn new(name: impl Into<String>, inn: Inn) -> Self {
+        Self {
+            phones: Vec::new(),
+            name: SupplierName(name.into()),
+            inn,
+        }
+    }
+
+    /// RU: Телефоны поставщика (тег 1171). EN: Supplier phones.
+    pub fn phones(mut self, phones: Vec<Phone>) -> Self {
+        self.phones = phones;
+        self
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        let phones: u64 = self.phones.iter().map(|p| p.0.len() as u64 + 4).sum();
+        validate::length(
+            "SupplierInfo.Name",
+            &self.name.0,
+            1,
+            239u64.saturating_sub(phones),
+        )
+    }
+}
+
 /// Requirements: <= 239 characters
 ///
 /// Тег ФФД: 1225
@@ -575,22 +1197,35 @@ struct SupplierName(String);
 /// composite — составной предмет расчета;
 /// another — иной предмет расчета.
 /// Если значение не передано, по умолчанию в онлайн-кассу отправляется признак предмета расчета commodity.
-#[derive(Serialize, Deserialize, Debug, Default)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
-enum PaymentObjectFF105 {
+pub enum PaymentObjectFF105 {
+    /// Товар.
     #[default]
     Commodity,
+    /// Подакцизный товар.
     Excise,
+    /// Работа.
     Job,
+    /// Услуга.
     Service,
+    /// Ставка азартной игры.
     GamblingBet,
+    /// Выигрыш азартной игры.
     GamblingPrize,
+    /// Лотерейный билет.
     Lottery,
+    /// Выигрыш лотереи.
     LotteryPrize,
+    /// Предоставление результатов интеллектуальной деятельности.
     IntellectualActivity,
+    /// Платеж.
     Payment,
+    /// Агентское вознаграждение.
     AgentCommission,
+    /// Составной предмет расчета.
     Composite,
+    /// Иной предмет расчета.
     Another,
 }
 
@@ -631,43 +1266,84 @@ enum PaymentObjectFF105 {
 /// - goods_without_marking_code — ТНМ;
 /// - goods_with_marking_code — ТМ;
 /// - another — иной предмет расчета.
-#[derive(Serialize, Deserialize, Debug, Default)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
-enum PaymentObjectFF12 {
+pub enum PaymentObjectFF12 {
+    /// Товар.
     #[default]
     Commodity,
+    /// Подакцизный товар.
     Excise,
+    /// Работа.
     Job,
+    /// Услуга.
     Service,
+    /// Ставка азартной игры.
     GamblingBet,
+    /// Выигрыш азартной игры.
     GamblingPrize,
+    /// Лотерейный билет.
     Lottery,
+    /// Выигрыш лотереи.
     LotteryPrize,
+    /// Предоставление, результатов интеллектуальной деятельности.
     IntellectualActivity,
+    /// Платеж.
     Payment,
+    /// Агентское вознаграждение.
     AgentCommission,
+    /// Выплата.
     Contribution,
+    /// Имущественное право.
     PropertyRights,
+    /// Внереализационный доход.
     Unrealization,
+    /// Иные платежи и взносы.
     TaxReduction,
+    /// Торговый сбор.
     TradeFee,
+    /// Курортный сбор.
     ResortTax,
+    /// Залог.
     Pledge,
+    /// Расход.
     IncomeDecrease,
+    /// Взносы на ОПС ИП.
     IePensionInsuranceWithoutPayments,
+    /// Взносы на ОПС.
     IePensionInsuranceWithPayments,
+    /// Взносы на ОМС ИП.
     IeMedicalInsuranceWithoutPayments,
+    /// Взносы на ОМС.
     IeMedicalInsuranceWithPayments,
+    /// Взносы на ОСС.
     SocialInsurance,
+    /// Платеж казино.
     CasinoChips,
+    /// Выдача ДС.
     AgentPayment,
+    /// АТНМ.
     ExcisableGoodsWithoutMarkingCode,
+    /// АТМ.
     ExcisableGoodsWithMarkingCode,
+    /// ТНМ.
     GoodsWithoutMarkingCode,
+    /// ТМ.
     GoodsWithMarkingCode,
+    /// Иной предмет расчета.
     Another,
 }
 
+impl PaymentObjectFF12 {
+    /// Товар подлежит обязательной маркировке: АТМ или ТМ.
+    fn is_marked(self) -> bool {
+        matches!(
+            self,
+            Self::ExcisableGoodsWithMarkingCode | Self::GoodsWithMarkingCode
+        )
+    }
+}
+
 /// Requirements: [none, vat0, vat5, vat7, vat10, vat20, vat22, vat105, vat107, vat110, vat120, vat122]
 ///
 /// Тег ФФД: 1199
@@ -686,20 +1362,32 @@ enum PaymentObjectFF12 {
 /// - vat110 — НДС чека по расчетной ставке 10/110;
 /// - vat120 — НДС чека по расчетной ставке 20/120;
 /// - vat122 — НДС чека по расчетной ставке 22/122 (с 01.01.2026).
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
-enum Tax {
+pub enum Tax {
+    /// Без НДС.
     None,
+    /// НДС по ставке 0%.
     Vat0,
+    /// НДС по ставке 5%.
     Vat5,
+    /// НДС по ставке 7%.
     Vat7,
+    /// НДС по ставке 10%.
     Vat10,
+    /// НДС по ставке 20%.
     Vat20,
+    /// НДС по ставке 22% (c 01.01.2026).
     Vat22,
+    /// НДС чека по расчетной ставке 5/105.
     Vat105,
+    /// НДС чека по расчетной ставке 7/107.
     Vat107,
+    /// НДС чека по расчетной ставке 10/110.
     Vat110,
+    /// НДС чека по расчетной ставке 20/120.
     Vat120,
+    /// НДС чека по расчетной ставке 22/122 (с 01.01.2026).
     Vat122,
 }
 
@@ -719,9 +1407,17 @@ enum Tax {
 /// Пример: igQVAAADMTIzNDU2Nzg5MDEyMwAAAAAAAQ==
 ///
 /// Если в запросе передается параметр Ean13, который не прошел валидацию, то вернется неуспешный ответ с текстом ошибки в параметре message = Неверный параметр Ean13.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Debug)]
 #[serde(transparent)]
-struct Ean13(String);
+pub struct Ean13(String);
+
+validated_string!(Ean13);
+
+impl Ean13 {
+    fn check(value: &str) -> Result<(), Error> {
+        validate::length("Ean13", value, 1, 300)
+    }
+}
 
 /// Requirements: <= 8 characters
 ///
@@ -729,41 +1425,443 @@ struct Ean13(String);
 ///
 /// целая часть — не больше 5 знаков;
 /// дробная — не больше 3 знаков для Атол и 2 знаков для CloudPayments.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Debug, Clone, Copy, PartialEq)]
 #[serde(transparent)]
-struct Quantity(NonZeroU16);
+pub struct Quantity(f64);
+
+impl Quantity {
+    /// RU: Количество или вес: больше нуля, целая часть — не больше 5 знаков, дробная — не
+    /// больше 3. EN: Quantity or weight: positive, up to 5 integer and 3 fractional digits.
+    pub fn new(quantity: f64) -> Result<Self, Error> {
+        let thousandths = quantity * 1000.0;
+        if !(quantity > 0.0 && quantity < 100_000.0)
+            || (thousandths - thousandths.round()).abs() > 1e-6
+        {
+            return Err(Error::Validation {
+                field: "Quantity",
+                reason: format!(
+                    "{quantity} must be positive with at most 5 integer and 3 fractional digits"
+                ),
+            });
+        }
+        Ok(Self(quantity))
+    }
+}
+
+impl From<NonZeroU16> for Quantity {
+    fn from(quantity: NonZeroU16) -> Self {
+        Self(f64::from(quantity.get()))
+    }
+}
+
+impl<'de> Deserialize<'de> for Quantity {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Self::new(f64::deserialize(deserializer)?).map_err(serde::de::Error::custom)
+    }
+}
 
 /// Код магазина. Для параметра ShopСode нужно использовать значение параметра Submerchant_ID, который возвращается в ответе при регистрации магазинов через XML. Если XML не используется, передавать поле не нужно.
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(transparent)]
-struct ShopCode(String);
+pub struct ShopCode(String);
+
+impl ShopCode {
+    /// RU: Код магазина из строки. EN: Shop code from a string.
+    pub fn new(code: impl Into<String>) -> Self {
+        Self(code.into())
+    }
+}
 
 /// JSON-объект с данными чека. Параметр обязательный, если подключена онлайн-касса.
+///
+/// Собирается через [ReceiptFFD105::builder] или [ReceiptFFD12::builder]; `FfdVersion`
+/// проставляется по варианту автоматически.
 #[derive(Serialize, Deserialize, Debug)]
-#[serde(rename_all = "PascalCase")]
+#[serde(tag = "FfdVersion")]
 pub enum Receipt {
-    FFD105 {
-        items: ReceiptItemsFFD105,
-        ffd_version: FfdVersion,
-        email: ReceiptEmail,
-        phone: ReceiptPhone,
-        taxation: Taxation,
-        payments: Payments,
+    /// Чек для онлайн-кассы с ФФД 1.05.
+    #[serde(rename = "1.05")]
+    FFD105(ReceiptFFD105),
+    /// Чек для онлайн-кассы с ФФД 1.2.
+    #[serde(rename = "1.2")]
+    FFD12(ReceiptFFD12),
+}
+
+/// RU: Максимальное количество позиций в чеке. EN: Maximum number of receipt items.
+const MAX_ITEMS: usize = 100;
+
+/// Чек для онлайн-кассы с ФФД 1.05.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct ReceiptFFD105 {
+    items: Vec<ItemFFD105>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    email: Option<ReceiptEmail>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    phone: Option<ReceiptPhone>,
+    taxation: Taxation,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    payments: Option<Payments>,
+}
+
+impl ReceiptFFD105 {
+    /// RU: Начать сборку чека ФФД 1.05 с системой налогообложения.
+    /// EN: Start building an FFD 1.05 receipt with the taxation system.
+    pub fn builder(taxation: Taxation) -> ReceiptFFD105Builder {
+        ReceiptFFD105Builder {
+            receipt: Self {
+                items: Vec::new(),
+                email: None,
+                phone: None,
+                taxation,
+                payments: None,
+            },
+        }
+    }
+}
+
+/// Сборщик чека ФФД 1.05. Обязательные теги проверяются в [ReceiptFFD105Builder::build].
+#[derive(Debug)]
+pub struct ReceiptFFD105Builder {
+    receipt: ReceiptFFD105,
+}
+
+impl ReceiptFFD105Builder {
+    /// RU: Добавить позицию. EN: Add an item.
+    pub fn item(mut self, item: ItemFFD105) -> Self {
+        self.receipt.items.push(item);
+        self
+    }
+
+    /// RU: Электронная почта клиента. EN: Customer email.
+    pub fn email(mut self, email: Email) -> Self {
+        self.receipt.email = Some(ReceiptEmail(email));
+        self
+    }
+
+    /// RU: Телефон клиента. EN: Customer phone.
+    pub fn phone(mut self, phone: Phone) -> Self {
+        self.receipt.phone = Some(ReceiptPhone(phone));
+        self
+    }
+
+    /// RU: Детали платежа. EN: Payment details.
+    pub fn payments(mut self, payments: Payments) -> Self {
+        self.receipt.payments = Some(payments);
+        self
+    }
+
+    /// RU: Проверить обязательные теги и получить чек.
+    /// EN: Check the mandatory tags and return the receipt.
+    pub fn build(self) -> Result<Receipt, Error> {
+        let receipt = self.receipt;
+
+        validate_header(
+            receipt.items.len(),
+            receipt.email.as_ref(),
+            receipt.phone.as_ref(),
+        )?;
+        for (index, item) in receipt.items.iter().enumerate() {
+            item.validate(index)?;
+        }
+
+        Ok(Receipt::FFD105(receipt))
+    }
+}
+
+/// Чек для онлайн-кассы с ФФД 1.2.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct ReceiptFFD12 {
+    items: Vec<ItemFFD12>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    client_info: Option<ClientInfo>,
+    taxation: Taxation,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    email: Option<ReceiptEmail>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    phone: Option<ReceiptPhone>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    customer: Option<Customer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    customer_inn: Option<CustomerInn>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    payments: Option<Payments>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    operating_check_props: Option<OperatingCheckProps>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sectoral_check_props: Option<SectoralCheckProps>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    add_user_prop: Option<AddUserProp>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    additional_check_props: Option<AdditionalCheckProps>,
+}
+
+impl ReceiptFFD12 {
+    /// RU: Начать сборку чека ФФД 1.2 с системой налогообложения.
+    /// EN: Start building an FFD 1.2 receipt with the taxation system.
+    pub fn builder(taxation: Taxation) -> ReceiptFFD12Builder {
+        ReceiptFFD12Builder {
+            receipt: Self {
+                items: Vec::new(),
+                client_info: None,
+                taxation,
+                email: None,
+                phone: None,
+                customer: None,
+                customer_inn: None,
+                payments: None,
+                operating_check_props: None,
+                sectoral_check_props: None,
+                add_user_prop: None,
+                additional_check_props: None,
+            },
+        }
+    }
+}
+
+/// Сборщик чека ФФД 1.2. Обязательные теги проверяются в [ReceiptFFD12Builder::build].
+#[derive(Debug)]
+pub struct ReceiptFFD12Builder {
+    receipt: ReceiptFFD12,
+}
+
+impl ReceiptFFD12Builder {
+    /// RU: Добавить позицию. EN: Add an item.
+    pub fn item(mut self, item: ItemFFD12) -> Self {
+        self.receipt.items.push(item);
+        self
+    }
+
+    /// RU: Электронная почта клиента. EN: Customer email.
+    pub fn email(mut self, email: Email) -> Self {
+        self.receipt.email = Some(ReceiptEmail(email));
+        self
+    }
+
+    /// RU: Телефон клиента. EN: Customer phone.
+    pub fn phone(mut self, phone: Phone) -> Self {
+        self.receipt.phone = Some(ReceiptPhone(phone));
+        self
+    }
+
+    /// RU: Информация по клиенту. EN: Customer information.
+    pub fn client_info(mut self, client_info: ClientInfo) -> Self {
+        self.receipt.client_info = Some(client_info);
+        self
+    }
+
+    /// RU: Идентификатор клиента: email или телефон. EN: Customer identifier, an email or a
+    /// phone number.
+    pub fn customer(mut self, customer: impl Into<String>) -> Self {
+        self.receipt.customer = Some(Customer(customer.into()));
+        self
+    }
+
+    /// RU: ИНН клиента. EN: Customer INN.
+    pub fn customer_inn(mut self, inn: Inn) -> Self {
+        self.receipt.customer_inn = Some(CustomerInn(inn));
+        self
+    }
+
+    /// RU: Детали платежа. EN: Payment details.
+    pub fn payments(mut self, payments: Payments) -> Self {
+        self.receipt.payments = Some(payments);
+        self
+    }
+
+    /// RU: Проверить обязательные теги и получить чек.
+    /// EN: Check the mandatory tags and return the receipt.
+    pub fn build(self) -> Result<Receipt, Error> {
+        let receipt = self.receipt;
+
+        validate_header(
+            receipt.items.len(),
+            receipt.email.as_ref(),
+            receipt.phone.as_ref(),
+        )?;
+        for (index, item) in receipt.items.iter().enumerate() {
+            item.validate(index)?;
+        }
+        if let Some(Customer(customer)) = &receipt.customer {
+            Customer::check(customer)?;
+        }
+        if let Some(client_info) = &receipt.client_info {
+            client_info.validate()?;
+        }
+
+        Ok(Receipt::FFD12(receipt))
+    }
+}
+
+/// Проверка количества позиций и контактов клиента.
+fn validate_header(
+    items: usize,
+    email: Option<&ReceiptEmail>,
+    phone: Option<&ReceiptPhone>,
+) -> Result<(), Error> {
+    if !(1..=MAX_ITEMS).contains(&items) {
+        return Err(Error::Validation {
+            field: "Items",
+            reason: format!("expected 1 to {MAX_ITEMS} items, got {items}"),
+        });
+    }
+    if email.is_none() && phone.is_none() {
+        return Err(Error::Validation {
+            field: "Email",
+            reason: "Email or Phone is required".to_string(),
+        });
+    }
+    Ok(())
+}
+
+impl Receipt {
+    /// RU: Версия ФФД чека. EN: Receipt FFD version.
+    pub fn ffd_version(&self) -> FfdVersion {
+        match self {
+            Receipt::FFD105(_) => FfdVersion::V105,
+            Receipt::FFD12(_) => FfdVersion::V12,
+        }
+    }
+
+    /// RU: Проверить суммы чека до отправки запроса:
+    ///
+    /// - `Price × Quantity` каждой позиции, округленное до копеек, совпадает с `Amount`;
+    /// - сумма `Amount` позиций равна сумме платежа `amount`;
+    /// - если передан `Payments`, `Electronic` равен `amount`, а сумма всех видов оплаты —
+    ///   сумме позиций.
+    ///
+    /// Вызывается из [InitPaymentReqBuilder::build](crate::InitPaymentReqBuilder::build).
+    ///
+    /// EN: Check receipt totals against the payment `amount` before any HTTP call and
+    /// return every mismatch found. Called by `InitPaymentReqBuilder::build`.
+    pub fn validate_against(&self, amount: Amount) -> Result<(), Vec<ReceiptError>> {
+        let (lines, payments): (Vec<_>, _) = match self {
+            Receipt::FFD105(receipt) => (
+                receipt
+                    .items
+                    .iter()
+                    .map(|i| line(&i.price, &i.quantity, &i.amount))
+                    .collect(),
+                receipt.payments.as_ref(),
+            ),
+            Receipt::FFD12(receipt) => (
+                receipt
+                    .items
+                    .iter()
+                    .map(|i| line(&i.price, &i.quantity, &i.amount))
+                    .collect(),
+                receipt.payments.as_ref(),
+            ),
+        };
+
+        let errors = check_totals(amount.kopecks(), &lines, payments);
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+/// Цена, количество и сумма позиции.
+type Line = (u32, f64, u32);
+
+fn line(price: &ItemPrice, quantity: &ItemQuantity, amount: &ItemAmount) -> Line {
+    (price.0.kopecks(), quantity.0.0, amount.0.kopecks())
+}
+
+fn check_totals(amount: u32, lines: &[Line], payments: Option<&Payments>) -> Vec<ReceiptError> {
+    let mut errors = Vec::new();
+
+    for (index, &(price, quantity, item_amount)) in lines.iter().enumerate() {
+        let expected = (f64::from(price) * quantity).round() as u32;
+        if expected != item_amount {
+            errors.push(ReceiptError::ItemAmount {
+                index,
+                price,
+                quantity,
+                amount: item_amount,
+            });
+        }
+    }
+
+    let items: u64 = lines.iter().map(|&(_, _, a)| u64::from(a)).sum();
+    if items != u64::from(amount) {
+        errors.push(ReceiptError::ItemsTotal { items, amount });
+    }
+
+    let Some(payments) = payments else {
+        return errors;
+    };
+
+    let electronic = payments.electronic.0.kopecks();
+    if electronic != amount {
+        errors.push(ReceiptError::Electronic { electronic, amount });
+    }
+
+    let total: u64 = [
+        Some(electronic),
+        payments.cash.as_ref().map(|p| p.0.kopecks()),
+        payments.advance_payment.as_ref().map(|p| p.0.kopecks()),
+        payments.credit.as_ref().map(|p| p.0.kopecks()),
+        payments.provision.as_ref().map(|p| p.0.kopecks()),
+    ]
+    .into_iter()
+    .flatten()
+    .map(u64::from)
+    .sum();
+    if total != items {
+        errors.push(ReceiptError::PaymentsTotal {
+            payments: total,
+            items,
+        });
+    }
+
+    errors
+}
+
+/// RU: Несоответствие сумм в чеке, см. [Receipt::validate_against].
+/// EN: Receipt totals mismatch.
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ReceiptError {
+    /// RU: `Price × Quantity` позиции не совпадает с ее `Amount`.
+    /// EN: Item `Price × Quantity` does not match its `Amount`.
+    #[error("Items[{index}]: Price {price} × Quantity {quantity} != Amount {amount}")]
+    ItemAmount {
+        /// RU: Индекс позиции в `Items`. EN: Index in `Items`.
+        index: usize,
+        /// RU: Цена в копейках. EN: Price in kopecks.
+        price: u32,
+        /// RU: Количество. EN: Quantity.
+        quantity: f64,
+        /// RU: Сумма позиции в копейках. EN: Item amount in kopecks.
+        amount: u32,
+    },
+    /// RU: Сумма позиций не равна сумме платежа. EN: Items total differs from the payment amount.
+    #[error("sum of Items amounts {items} != Amount {amount}")]
+    ItemsTotal {
+        /// RU: Сумма позиций. EN: Items total.
+        items: u64,
+        /// RU: Сумма платежа. EN: Payment amount.
+        amount: u32,
     },
-    FFD12 {
-        items: ReceiptItemsFFD12,
-        ffd_vesrion: FfdVersion,
-        client_info: ClientInfo,
-        taxation: Taxation,
-        email: ReceiptEmail,
-        phone: ReceiptPhone,
-        customer: Customer,
-        customer_inn: CustomerInn,
-        payments: Payments,
-        operating_check_props: OperatingCheckProps,
-        sectoral_check_props: SectoralCheckProps,
-        add_user_prop: AddUserProp,
-        additional_check_props: AdditionalCheckProps,
+    /// RU: `Payments.Electronic` не равен сумме платежа. EN: Electronic differs from the payment amount.
+    #[error("Payments.Electronic {electronic} != Amount {amount}")]
+    Electronic {
+        /// RU: Безналичная оплата. EN: Electronic payment.
+        electronic: u32,
+        /// RU: Сумма платежа. EN: Payment amount.
+        amount: u32,
+    },
+    /// RU: Сумма всех видов оплаты не равна сумме позиций.
+    /// EN: Sum of all payment kinds differs from the items total.
+    #[error("sum of Payments {payments} != sum of Items amounts {items}")]
+    PaymentsTotal {
+        /// RU: Сумма всех видов оплаты. EN: Sum of all payment kinds.
+        payments: u64,
+        /// RU: Сумма позиций. EN: Items total.
+        items: u64,
     },
 }
 
@@ -772,25 +1870,87 @@ pub enum Receipt {
 /// ИНН клиента.
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(transparent)]
-struct CustomerInn(Inn);
+pub struct CustomerInn(Inn);
 
 /// Тег ФФД: 1227
 ///
 /// Идентификатор/имя клиента.
 ///
 /// В параметре можно передавать только email или номер телефона.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Debug)]
 #[serde(transparent)]
-struct Customer(String);
+pub struct Customer(String);
+
+validated_string!(Customer);
+
+impl Customer {
+    fn check(value: &str) -> Result<(), Error> {
+        Email::check(value)
+            .or_else(|_| Phone::check(value))
+            .map_err(|_| Error::Validation {
+                field: "Customer",
+                reason: format!("expected an email or a +{{digits}} phone, got {value:?}"),
+            })
+    }
+}
 
 /// Информация по клиенту.
-#[derive(Serialize, Deserialize, Debug)]
-struct ClientInfo {
-    birthdate: Birthdate,
-    citizenship: Citizenship,
-    document_code: DocumentCode,
-    document_data: DocumentData,
-    address: Address,
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(rename_all = "PascalCase")]
+pub struct ClientInfo {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    birthdate: Option<Birthdate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    citizenship: Option<Citizenship>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    document_code: Option<DocumentCode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    document_data: Option<DocumentData>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    address: Option<Address>,
+}
+
+impl ClientInfo {
+    /// RU: Пустая информация по клиенту. EN: Empty customer information.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// RU: Дата рождения. EN: Date of birth.
+    pub fn birthdate(mut self, birthdate: NaiveDate) -> Self {
+        self.birthdate = Some(Birthdate(birthdate.format("%d.%m.%Y").to_string()));
+        self
+    }
+
+    /// RU: Код страны гражданства по ОКСМ. EN: Citizenship country code.
+    pub fn citizenship(mut self, code: impl Into<String>) -> Self {
+        self.citizenship = Some(Citizenship(code.into()));
+        self
+    }
+
+    /// RU: Вид и реквизиты документа, удостоверяющего личность.
+    /// EN: Identity document kind and details.
+    pub fn document(mut self, code: DocumentCode, data: impl Into<String>) -> Self {
+        self.document_code = Some(code);
+        self.document_data = Some(DocumentData(data.into()));
+        self
+    }
+
+    /// RU: Адрес клиента-грузополучателя. EN: Consignee address.
+    pub fn address(mut self, address: impl Into<String>) -> Self {
+        self.address = Some(Address(address.into()));
+        self
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        if let Some(Citizenship(code)) = &self.citizenship {
+            validate::digits("Citizenship", code, &[3])?;
+        }
+        if let Some(Address(address)) = &self.address {
+            validate::length("Address", address, 1, 256)?;
+        }
+        Ok(())
+    }
 }
 
 /// Тег ФФД: 1243
@@ -827,34 +1987,48 @@ struct Citizenship(String);
 /// - 37 — удостоверение беженца.
 /// - 38 — иные документы, признаваемые документами, удостоверяющими личность лиц без гражданства в соответствии с  законодательством Российской Федерации и международным договором Российской Федерации.
 /// - 40 — документ, удостоверяющий личность лица, не имеющего действительного документа, удостоверяющего личность, на период рассмотрения заявления о признании гражданином Российской Федерации или о приеме в гражданство Российской  Федерации.
-#[derive(Serialize, Deserialize, Debug)]
-enum DocumentCode {
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DocumentCode {
+    /// Паспорт гражданина Российской Федерации.
     #[serde(rename = "21")]
     C21,
+    /// Паспорт гражданина Российской Федерации, дипломатический паспорт, служебный паспорт, удостоверяющие личность гражданина Российской Федерации за пределами Российской Федерации.
     #[serde(rename = "22")]
     C22,
+    /// Временное удостоверение личности гражданина Российской Федерации, выдаваемое на период оформления паспорта гражданина Российской Федерации.
     #[serde(rename = "26")]
     C26,
+    /// Свидетельство о рождении гражданина Российской Федерации. Для граждан Российской Федерации в возрасте до 14 лотереи.
     #[serde(rename = "27")]
     C27,
+    /// Иные документы, признаваемые документами, удостоверяющими личность гражданина Российской Федерации в соответствии с законодательством Российской Федерации.
     #[serde(rename = "28")]
     C28,
+    /// Паспорт иностранного гражданина.
     #[serde(rename = "31")]
     C31,
+    /// Иные документы, признаваемые документами, удостоверяющими личность иностранного гражданина в соответствии с законодательством Российской Федерации и международным договором Российской Федерации.
     #[serde(rename = "32")]
     C32,
+    /// Документ, выданный иностранным государством и признаваемый в соответствии с международным договором Российской Федерации в качестве документа, удостоверяющего личность лица безгражданства.
     #[serde(rename = "33")]
     C33,
+    /// Вид на жительство, для лиц без гражданства.
     #[serde(rename = "34")]
     C34,
+    /// Разрешение на временное проживание, для лиц без гражданства.
     #[serde(rename = "35")]
     C35,
+    /// Свидетельство о рассмотрении ходатайства о признании лица без гражданства беженцем на территории Российской Федерации по существу.
     #[serde(rename = "36")]
     C36,
+    /// Удостоверение беженца.
     #[serde(rename = "37")]
     C37,
+    /// Иные документы, признаваемые документами, удостоверяющими личность лиц без гражданства в соответствии с  законодательством Российской Федерации и международным договором Российской Федерации.
     #[serde(rename = "38")]
     C38,
+    /// Документ, удостоверяющий личность лица, не имеющего действительного документа, удостоверяющего личность, на период рассмотрения заявления о признании гражданином Российской Федерации или о приеме в гражданство Российской  Федерации.
     #[serde(rename = "40")]
     C40,
 }
@@ -880,10 +2054,12 @@ struct Address(String);
 /// Default: 1.05
 ///
 /// Версия ФФД.
-#[derive(Serialize, Deserialize, Debug, Default)]
-enum FfdVersion {
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum FfdVersion {
+    /// ФФД 1.2.
     #[serde(rename = "1.2")]
     V12,
+    /// ФФД 1.05.
     #[default]
     #[serde(rename = "1.05")]
     V105,
@@ -896,7 +2072,7 @@ enum FfdVersion {
 /// Электронная почта клиента. Параметр обязательный, если не передан Phone.
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(transparent)]
-struct ReceiptEmail(Email);
+pub struct ReceiptEmail(Email);
 
 /// Requirements: <= 64 characters
 ///
@@ -905,7 +2081,7 @@ struct ReceiptEmail(Email);
 /// Телефон клиента в формате +{Ц}. Параметр обязательный, если не передан Email.
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(transparent)]
-struct ReceiptPhone(Phone);
+pub struct ReceiptPhone(Phone);
 
 /// Requirements: [osn, usn_income, usn_income_outcome, esn, patent]
 ///
@@ -918,34 +2094,43 @@ struct ReceiptPhone(Phone);
 /// - usn_income_outcome — упрощенная СН (доходы минус расходы). Налоговая автоматически определит АУСН по ИНН и пробьет чеки с нужной СНО;
 /// - esn — единый сельскохозяйственный налог;
 /// - patent — патентная СН.
-#[derive(Serialize, Deserialize, Debug)]
-#[serde(rename_all = "lowercase")]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
 pub enum Taxation {
+    /// Общая СН.
     Osn,
+    /// Упрощенная СН (доходы).
     UsnIncome,
+    /// Упрощенная СН (доходы минус расходы).
     UsnIncomeOutcome,
+    /// Единый сельскохозяйственный налог.
     Esn,
+    /// Патентная СН.
     Patent,
 }
 
+/// Requirements: <= 64 characters
+///
 /// Инвариант электронной почты
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Debug)]
 #[serde(transparent)]
 pub struct Email(String);
 
-/// Массив позиций чека с информацией о товарах. Количество товаров в чеке — не больше 100.
-#[derive(Serialize, Deserialize, Debug)]
-#[serde(transparent)]
-struct ReceiptItemsFFD105(Vec<ItemFFD105>);
+validated_string!(Email);
 
-/// Массив с информацией о товарах. Количество товаров в чеке — не больше 100.
-///
-/// Параметры, которые предусмотрены в протоколе для отправки чеков по маркируемым товарам, не являются обязательными для товаров без маркировки.
-///
-/// Если используется ФФД 1.2, но реализуемый товар не подлежит маркировке, поля можно не отправлять или отправить со значением null.
-#[derive(Serialize, Deserialize, Debug)]
-#[serde(transparent)]
-struct ReceiptItemsFFD12(Vec<ItemFFD12>);
+impl Email {
+    /// RU: Адрес электронной почты из строки. EN: Email address from a string.
+    pub fn new(email: impl Into<String>) -> Result<Self, Error> {
+        let email = email.into();
+        Self::check(&email)?;
+        Ok(Self(email))
+    }
+
+    fn check(value: &str) -> Result<(), Error> {
+        validate::email("Email", value)?;
+        validate::length("Email", value, 1, 64)
+    }
+}
 
 /// Детали платежа.
 ///
@@ -956,12 +2141,54 @@ struct ReceiptItemsFFD12(Vec<ItemFFD12>);
 #[serde(rename_all = "PascalCase")]
 pub struct Payments {
     electronic: Electronic,
+    #[serde(skip_serializing_if = "Option::is_none")]
     cash: Option<Cash>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     advance_payment: Option<AdvancePayment>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     credit: Option<Credit>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     provision: Option<Provision>,
 }
 
+impl Payments {
+    /// RU: Детали платежа с безналичной оплатой, равной сумме платежа.
+    /// EN: Payment details with the electronic part equal to the payment amount.
+    pub fn new(electronic: Amount) -> Self {
+        Self {
+            electronic: Electronic(electronic),
+            cash: None,
+            advance_payment: None,
+            credit: None,
+            provision: None,
+        }
+    }
+
+    /// RU: Оплата наличными. EN: Cash part.
+    pub fn cash(mut self, cash: Amount) -> Self {
+        self.cash = Some(Cash(cash));
+        self
+    }
+
+    /// RU: Предварительная оплата (аванс). EN: Advance payment part.
+    pub fn advance_payment(mut self, advance_payment: Amount) -> Self {
+        self.advance_payment = Some(AdvancePayment(advance_payment));
+        self
+    }
+
+    /// RU: Постоплата (кредит). EN: Credit part.
+    pub fn credit(mut self, credit: Amount) -> Self {
+        self.credit = Some(Credit(credit));
+        self
+    }
+
+    /// RU: Иная форма оплаты. EN: Other form of payment.
+    pub fn provision(mut self, provision: Amount) -> Self {
+        self.provision = Some(Provision(provision));
+        self
+    }
+}
+
 /// Requirements: <= 14 characters
 ///
 /// Тег ФФД: 1031.
@@ -1006,3 +2233,404 @@ struct Credit(Amount);
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(transparent)]
 struct Provision(Amount);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn validated_strings() {
+        assert!(Phone::try_from("+79031234567").is_ok());
+        assert!(Phone::try_from("89031234567").is_err());
+        assert!(Phone::try_from("+7 903 123-45-67").is_err());
+        assert!(Email::try_from("a@test.ru").is_ok());
+        assert!(Email::try_from("a.test.ru").is_err());
+        assert!(Email::new(format!("{}@test.ru", "a".repeat(57))).is_err());
+        assert!(Phone::new("+").is_err());
+        assert!(Inn::try_from("7707083893").is_ok());
+        assert!(Inn::try_from("77070838").is_err());
+        assert!(ItemName::try_from("").is_err());
+        assert!(Ean13::try_from("0".repeat(301).as_str()).is_err());
+
+        let err = serde_json::from_str::<Email>(r#""not-an-email""#).unwrap_err();
+        assert!(err.to_string().contains("invalid Email"));
+        assert!(serde_json::from_str::<Phone>(r#""+79031234567""#).is_ok());
+    }
+
+    fn payments(electronic: u32, cash: Option<u32>) -> Payments {
+        let amount = |k| Amount::new(std::num::NonZeroU32::new(k).unwrap());
+        let payments = Payments::new(amount(electronic));
+        match cash {
+            Some(cash) => payments.cash(amount(cash)),
+            None => payments,
+        }
+    }
+
+    #[test]
+    fn consistent_totals() {
+        let lines = [
+            (10000, 1.0, 10000),
+            (20000, 2.0, 40000),
+            (33333, 0.333, 11100),
+        ];
+
+        assert!(check_totals(61100, &lines, Some(&payments(61100, None))).is_empty());
+    }
+
+    #[test]
+    fn inconsistent_totals() {
+        let lines = [
+            (10000, 1.0, 10000),
+            (20000, 2.0, 39999),
+            (33333, 0.333, 11105),
+        ];
+
+        assert_eq!(
+            check_totals(50000, &lines, Some(&payments(45000, Some(5000)))),
+            vec![
+                ReceiptError::ItemAmount {
+                    index: 1,
+                    price: 20000,
+                    quantity: 2.0,
+                    amount: 39999
+                },
+                ReceiptError::ItemAmount {
+                    index: 2,
+                    price: 33333,
+                    quantity: 0.333,
+                    amount: 11105
+                },
+                ReceiptError::ItemsTotal {
+                    items: 61104,
+                    amount: 50000
+                },
+                ReceiptError::Electronic {
+                    electronic: 45000,
+                    amount: 50000
+                },
+                ReceiptError::PaymentsTotal {
+                    payments: 50000,
+                    items: 61104
+                },
+            ]
+        );
+    }
+
+    fn rub(rubles: u32) -> Amount {
+        Amount::new(std::num::NonZeroU32::new(rubles * 100).unwrap())
+    }
+
+    #[test]
+    fn validate_against_payment_amount() {
+        let item = |quantity, amount| {
+            ItemFFD105::new(
+                ItemName::try_from("Кофе").unwrap(),
+                rub(100),
+                Quantity::new(quantity).unwrap(),
+                amount,
+                Tax::None,
+            )
+        };
+        let receipt = ReceiptFFD105::builder(Taxation::Osn)
+            .email(Email::try_from("a@test.ru").unwrap())
+            .item(item(2.0, rub(200)))
+            .item(item(1.5, rub(149)))
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            receipt.validate_against(rub(300)),
+            Err(vec![
+                ReceiptError::ItemAmount {
+                    index: 1,
+                    price: 10000,
+                    quantity: 1.5,
+                    amount: 14900
+                },
+                ReceiptError::ItemsTotal {
+                    items: 34900,
+                    amount: 30000
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn build_ffd105_receipt() {
+        let receipt = ReceiptFFD105::builder(Taxation::UsnIncome)
+            .email(Email::try_from("a@test.ru").unwrap())
+            .item(ItemFFD105::new(
+                ItemName::try_from("Подарочная карта").unwrap(),
+                rub(100),
+                Quantity::new(2.0).unwrap(),
+                rub(200),
+                Tax::Vat20,
+            ))
+            .payments(Payments::new(rub(200)))
+            .build()
+            .unwrap();
+
+        assert_eq!(receipt.ffd_version(), FfdVersion::V105);
+        assert!(receipt.validate_against(rub(200)).is_ok());
+        assert_eq!(
+            serde_json::to_value(&receipt).unwrap(),
+            serde_json::json!({
+                "FfdVersion": "1.05",
+                "Items": [{
+                    "Name": "Подарочная карта",
+                    "Price": 10000,
+                    "Quantity": 2.0,
+                    "Amount": 20000,
+                    "PaymentMethod": "full_payment",
+                    "PaymentObject": "commodity",
+                    "Tax": "vat20"
+                }],
+                "Email": "a@test.ru",
+                "Taxation": "usn_income",
+                "Payments": {"Electronic": 20000}
+            })
+        );
+    }
+
+    #[test]
+    fn build_ffd12_receipt() {
+        let receipt = ReceiptFFD12::builder(Taxation::Osn)
+            .phone(Phone::try_from("+79031234567").unwrap())
+            .customer_inn(Inn::try_from("500100732259").unwrap())
+            .item(
+                ItemFFD12::new(
+                    ItemName::try_from("Ботинки").unwrap(),
+                    rub(5000),
+                    Quantity::new(1.0).unwrap(),
+                    rub(5000),
+                    Tax::Vat20,
+                    MeasurementUnit::Piece,
+                )
+                .payment_object(PaymentObjectFF12::GoodsWithMarkingCode)
+                .mark_code(MarkCode::new(MarkCodeType::Ean13, "4600000000000"))
+                .agent(
+                    AgentData::new(AgentSign::Attorney),
+                    SupplierInfo::new("ООО Поставщик", Inn::try_from("7707083893").unwrap()),
+                ),
+            )
+            .build()
+            .unwrap();
+
+        let json = serde_json::to_value(&receipt).unwrap();
+        assert_eq!(json["FfdVersion"], "1.2");
+        assert_eq!(json["CustomerInn"], "500100732259");
+        let item = &json["Items"][0];
+        assert_eq!(item["PaymentObject"], "goods_with_marking_code");
+        assert_eq!(item["MeasurementUnit"], "шт");
+        assert_eq!(item["MarkProcessingMode"], "0");
+        assert_eq!(
+            item["MarkCode"],
+            serde_json::json!({"MarkCodeType": "EAN13", "Value": "4600000000000"})
+        );
+        assert_eq!(
+            item["AgentData"],
+            serde_json::json!({"AgentSign": "attorney"})
+        );
+        assert_eq!(item["SupplierInfo"]["Inn"], "7707083893");
+
+        let Receipt::FFD12(parsed) = serde_json::from_value(json).unwrap() else {
+            panic!("expected FFD 1.2 receipt");
+        };
+        assert_eq!(parsed.items.len(), 1);
+    }
+
+    #[test]
+    fn build_checks_mandatory_tags() {
+        let field = |res: Result<Receipt, Error>| match res {
+            Err(Error::Validation { field, .. }) => field,
+            other => panic!("expected validation error, got {other:?}"),
+        };
+        let item = || {
+            ItemFFD105::new(
+                ItemName::try_from("Товар").unwrap(),
+                rub(1),
+                Quantity::new(1.0).unwrap(),
+                rub(1),
+                Tax::None,
+            )
+        };
+        let email = || Email::try_from("a@test.ru").unwrap();
+        let supplier = || SupplierInfo::new("ООО Поставщик", Inn::try_from("7707083893").unwrap());
+
+        assert_eq!(
+            field(ReceiptFFD105::builder(Taxation::Osn).email(email()).build()),
+            "Items"
+        );
+        assert_eq!(
+            field(ReceiptFFD105::builder(Taxation::Osn).item(item()).build()),
+            "Email"
+        );
+        assert_eq!(
+            field(
+                ReceiptFFD105::builder(Taxation::Osn)
+                    .email(email())
+                    .item(
+                        item().agent(
+                            AgentData::new(AgentSign::BankPayingAgent)
+                                .operation_name("Перевод")
+                                .phones(vec![Phone::try_from("+79031234567").unwrap()]),
+                            supplier(),
+                        )
+                    )
+                    .build()
+            ),
+            "OperatorName"
+        );
+        assert_eq!(
+            field(
+                ReceiptFFD105::builder(Taxation::Osn)
+                    .email(email())
+                    .item(
+                        item().agent(
+                            AgentData::new(AgentSign::BankPayingAgent)
+                                .operation_name("Перевод")
+                                .phones(vec![Phone::try_from("+79031234567").unwrap()])
+                                .operator("Банк", "Москва", Inn::try_from("500100732259").unwrap()),
+                            supplier(),
+                        )
+                    )
+                    .build()
+            ),
+            "OperatorInn"
+        );
+        assert_eq!(
+            field(
+                ReceiptFFD105::builder(Taxation::Osn)
+                    .email(email())
+                    .item(item().agent(
+                        AgentData::new(AgentSign::Another),
+                        SupplierInfo::new("я".repeat(240), Inn::try_from("7707083893").unwrap()),
+                    ))
+                    .build()
+            ),
+            "SupplierInfo.Name"
+        );
+
+        let ffd12 = |item: ItemFFD12| {
+            ReceiptFFD12::builder(Taxation::Osn)
+                .email(email())
+                .item(item)
+        };
+        let item12 = |payment_object| {
+            ItemFFD12::new(
+                ItemName::try_from("Ботинки").unwrap(),
+                rub(1),
+                Quantity::new(1.0).unwrap(),
+                rub(1),
+                Tax::None,
+                MeasurementUnit::Piece,
+            )
+            .payment_object(payment_object)
+        };
+        let mark_code = || MarkCode::new(MarkCodeType::Ean13, "4600000000000");
+        let half = || MarkQuantity::new(1, 2).unwrap();
+
+        for payment_object in [
+            PaymentObjectFF12::GoodsWithMarkingCode,
+            PaymentObjectFF12::ExcisableGoodsWithMarkingCode,
+        ] {
+            assert_eq!(field(ffd12(item12(payment_object)).build()), "MarkCode");
+        }
+        assert_eq!(
+            field(
+                ffd12(item12(PaymentObjectFF12::GoodsWithoutMarkingCode).mark_quantity(half()))
+                    .build()
+            ),
+            "MarkQuantity"
+        );
+        assert_eq!(
+            field(
+                ffd12(
+                    ItemFFD12::new(
+                        ItemName::try_from("Сыр").unwrap(),
+                        rub(1),
+                        Quantity::new(1.0).unwrap(),
+                        rub(1),
+                        Tax::None,
+                        MeasurementUnit::Kilogram,
+                    )
+                    .payment_object(PaymentObjectFF12::GoodsWithMarkingCode)
+                    .mark_code(mark_code())
+                    .mark_quantity(half())
+                )
+                .build()
+            ),
+            "MarkQuantity"
+        );
+        assert!(
+            ffd12(
+                item12(PaymentObjectFF12::GoodsWithMarkingCode)
+                    .mark_code(mark_code())
+                    .mark_quantity(half())
+            )
+            .build()
+            .is_ok()
+        );
+        for excise in ["-1.00", "123456789", "1.005", "1,50", ""] {
+            assert_eq!(
+                field(ffd12(item12(PaymentObjectFF12::Excise).excise(excise)).build()),
+                "Excise"
+            );
+        }
+        assert!(
+            ffd12(item12(PaymentObjectFF12::Excise).excise("12345678.50"))
+                .build()
+                .is_ok()
+        );
+        assert_eq!(
+            field(
+                ffd12(item12(PaymentObjectFF12::Commodity))
+                    .customer("Иван Иванов")
+                    .build()
+            ),
+            "Customer"
+        );
+        for customer in ["a@test.ru", "+79031234567"] {
+            assert!(
+                ffd12(item12(PaymentObjectFF12::Commodity))
+                    .customer(customer)
+                    .build()
+                    .is_ok()
+            );
+        }
+
+        assert!(Quantity::new(1.5).is_ok());
+        assert!(Quantity::new(0.0).is_err());
+        assert!(Quantity::new(0.0001).is_err());
+        assert!(Quantity::new(f64::NAN).is_err());
+        assert_eq!(
+            Quantity::from(NonZeroU16::new(2).unwrap()),
+            Quantity::new(2.0).unwrap()
+        );
+        assert!(serde_json::from_str::<Quantity>("0").is_err());
+        assert!(serde_json::from_str::<Quantity>("-1").is_err());
+        assert!(serde_json::from_str::<Quantity>("1.5").is_ok());
+        assert!(MarkQuantity::new(2, 1).is_err());
+    }
+
+    #[test]
+    fn inn_control_digits() {
+        let legal = Inn::try_from("7707083893").unwrap();
+        let individual = Inn::try_from("500100732259").unwrap();
+
+        assert_eq!(legal.kind(), InnKind::LegalEntity);
+        assert_eq!(individual.kind(), InnKind::Individual);
+        assert!(Inn::try_from("7707083894").is_err());
+        assert!(Inn::try_from("500100732258").is_err());
+        assert!(Inn::try_from("500100732269").is_err());
+        assert!(serde_json::from_str::<Inn>(r#""7830002294""#).is_err());
+
+        assert!(
+            individual
+                .require_kind("OperatorInn", InnKind::LegalEntity)
+                .is_err()
+        );
+        assert!(serde_json::from_str::<OperatorInn>(r#""7707083893""#).is_ok());
+        assert!(serde_json::from_str::<OperatorInn>(r#""500100732259""#).is_err());
+    }
+}
@@ -0,0 +1,101 @@
+use crate::{ApiVersion, Client, Error, PaymentId, Service, client::svg_response};
+use serde::Deserialize;
+use url::Url;
+
+/// Ответ на запрос ссылки SberPay.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct SberPayLinkRes {
+    /// Успешность прохождения запроса.
+    pub success: bool,
+    /// Код ошибки. `0` — если ошибки не произошло.
+    pub error_code: String,
+    /// Краткое описание ошибки.
+    pub message: Option<String>,
+    /// Подробное описание ошибки.
+    pub details: Option<String>,
+    /// Параметры ссылки.
+    pub params: Option<SberPayLinkParams>,
+}
+
+/// Ссылка на оплату в SberPay.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct SberPayLinkParams {
+    /// Ссылка для перехода в приложение СберБанк Онлайн.
+    pub redirect_url: Url,
+}
+
+impl Client {
+    /// RU: Получить ссылку на оплату в SberPay (`/v2/SberPay/transactions/{PaymentId}/link`).
+    /// EN: Get the SberPay payment link for a mobile device.
+    pub async fn sberpay_link(&self, payment_id: &PaymentId) -> Result<SberPayLinkRes, Error> {
+        self.get(Service::SberPay, &sberpay_link_path(payment_id), &())
+            .await
+    }
+
+    /// RU: Получить SVG-изображение QR-кода SberPay (`/v2/SberPay/{PaymentId}/QR`).
+    /// EN: Get the SberPay QR code as an SVG image.
+    pub async fn sberpay_qr(&self, payment_id: &PaymentId) -> Result<String, Error> {
+        let url = self.url(
+            Service::SberPay,
+            ApiVersion::V2,
+            &sberpay_qr_path(payment_id),
+        );
+
+        svg_response(self.send_raw(self.client.get(url)).await?)
+    }
+}
+
+fn sberpay_link_path(payment_id: &PaymentId) -> String {
+    format!("transactions/{}/link", payment_id.as_str())
+}
+
+fn sberpay_qr_path(payment_id: &PaymentId) -> String {
+    format!("{}/QR", payment_id.as_str())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{TEST_BASE, client::build_url};
+
+    #[test]
+    fn parse_link() {
+        let json = r#"{"Success":true,"ErrorCode":"0","Params":{"RedirectUrl":"sberpay://invoicing/v2?bankInvoiceId=e6b9b6f4"}}"#;
+        let res: SberPayLinkRes = serde_json::from_str(json).unwrap();
+
+        assert_eq!(res.params.unwrap().redirect_url.scheme(), "sberpay");
+    }
+
+    #[test]
+    fn urls() {
+        let payment_id = PaymentId::new("700");
+        let url = |path: String| build_url(TEST_BASE, Service::SberPay, ApiVersion::V2, &path);
+
+        assert_eq!(
+            url(sberpay_link_path(&payment_id)),
+            "https://rest-api-test.tinkoff.ru/v2/SberPay/transactions/700/link"
+        );
+        assert_eq!(
+            url(sberpay_qr_path(&payment_id)),
+            "https://rest-api-test.tinkoff.ru/v2/SberPay/700/QR"
+        );
+    }
+
+    #[test]
+    fn qr_rejects_error_body() {
+        let json = r#"{"Success":false,"ErrorCode":"3107","Message":"SberPay недоступен"}"#;
+
+        match svg_response(json.to_string()) {
+            Err(Error::Api { code, message, .. }) => {
+                assert_eq!(code, "3107");
+                assert_eq!(message, "SberPay недоступен");
+            }
+            other => panic!("expected Error::Api, got {other:?}"),
+        }
+
+        let svg = "<?xml version=\"1.0\"?><svg></svg>";
+        assert_eq!(svg_response(svg.to_string()).unwrap(), svg);
+    }
+}
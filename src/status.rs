@@ -0,0 +1,302 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+
+/// Requirements: <= 20 characters
+///
+/// Статус платежа.
+///
+/// [Подробнее](https://developer.tbank.ru/eacq/intro/developer/statuses)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PaymentStatus {
+    /// NEW — платеж создан.
+    New,
+    /// FORM_SHOWED — покупатель открыл платежную форму.
+    FormShowed,
+    /// AUTHORIZING — платеж обрабатывается.
+    Authorizing,
+    /// 3DS_CHECKING — покупатель проходит проверку 3-D Secure.
+    ThreeDsChecking,
+    /// 3DS_CHECKED — покупатель прошел проверку 3-D Secure.
+    ThreeDsChecked,
+    /// AUTHORIZED — средства заблокированы, но не списаны.
+    Authorized,
+    /// CONFIRMING — подтверждение платежа обрабатывается.
+    Confirming,
+    /// CONFIRMED — платеж подтвержден, средства списаны.
+    Confirmed,
+    /// REVERSING — мерчант запросил отмену авторизации.
+    Reversing,
+    /// PARTIAL_REVERSED — частичная отмена авторизации.
+    PartialReversed,
+    /// REVERSED — полная отмена авторизации.
+    Reversed,
+    /// REFUNDING — мерчант запросил возврат.
+    Refunding,
+    /// PARTIAL_REFUNDED — частичный возврат.
+    PartialRefunded,
+    /// REFUNDED — полный возврат.
+    Refunded,
+    /// REJECTED — платеж отклонен банком.
+    Rejected,
+    /// DEADLINE_EXPIRED — покупатель не завершил платеж в срок жизни ссылки.
+    DeadlineExpired,
+    /// Статус, неизвестный SDK.
+    Unknown(String),
+}
+
+impl PaymentStatus {
+    /// RU: Статус в том виде, в котором его передает API.
+    /// EN: Status as transmitted by the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::New => "NEW",
+            Self::FormShowed => "FORM_SHOWED",
+            Self::Authorizing => "AUTHORIZING",
+            Self::ThreeDsChecking => "3DS_CHECKING",
+            Self::ThreeDsChecked => "3DS_CHECKED",
+            Self::Authorized => "AUTHORIZED",
+            Self::Confirming => "CONFIRMING",
+            Self::Confirmed => "CONFIRMED",
+            Self::Reversing => "REVERSING",
+            Self::PartialReversed => "PARTIAL_REVERSED",
+            Self::Reversed => "REVERSED",
+            Self::Refunding => "REFUNDING",
+            Self::PartialRefunded => "PARTIAL_REFUNDED",
+            Self::Refunded => "REFUNDED",
+            Self::Rejected => "REJECTED",
+            Self::DeadlineExpired => "DEADLINE_EXPIRED",
+            Self::Unknown(s) => s,
+        }
+    }
+}
+
+impl PaymentStatus {
+    /// RU: Статусы, в которые платеж может перейти непосредственно из текущего.
+    /// EN: Statuses directly reachable from this one.
+    pub fn next_statuses(&self) -> &'static [PaymentStatus] {
+        use PaymentStatus::*;
+
+        match self {
+            New => &[FormShowed, Authorizing, Rejected, DeadlineExpired],
+            FormShowed => &[Authorizing, Rejected, DeadlineExpired],
+            Authorizing => &[ThreeDsChecking, Authorized, Rejected],
+            ThreeDsChecking => &[ThreeDsChecked, Rejected],
+            ThreeDsChecked => &[Authorizing, Authorized, Rejected],
+            Authorized => &[Confirming, Confirmed, Reversing, PartialReversed, Reversed],
+            Confirming => &[Confirmed],
+            Confirmed => &[Refunding, PartialRefunded, Refunded],
+            Reversing => &[PartialReversed, Reversed],
+            PartialReversed => &[Confirming, Confirmed, Reversing, PartialReversed, Reversed],
+            Refunding => &[PartialRefunded, Refunded],
+            PartialRefunded => &[Refunding, PartialRefunded, Refunded],
+            Reversed | Refunded | Rejected | DeadlineExpired | Unknown(_) => &[],
+        }
+    }
+
+    /// RU: Может ли платеж, находящийся в текущем статусе, когда-либо получить статус `next`.
+    /// EN: Whether a payment in this status can ever reach `next`.
+    ///
+    /// Промежуточные статусы учитываются: уведомления приходят не на каждый статус, поэтому
+    /// NEW → CONFIRMED допустим. Повтор того же статуса допустим только для частичных
+    /// отмен и возвратов. Для [PaymentStatus::Unknown] всегда `false`.
+    pub fn can_transition_to(&self, next: &PaymentStatus) -> bool {
+        let mut visited: Vec<&PaymentStatus> = Vec::new();
+        let mut queue: Vec<&PaymentStatus> = self.next_statuses().iter().collect();
+
+        while let Some(status) = queue.pop() {
+            if status == next {
+                return true;
+            }
+            if !visited.contains(&status) {
+                visited.push(status);
+                queue.extend(status.next_statuses());
+            }
+        }
+
+        false
+    }
+
+    /// RU: Конечный статус — платеж больше не изменится.
+    /// EN: Terminal status; the payment will not change any more.
+    pub fn is_final(&self) -> bool {
+        matches!(
+            self,
+            Self::Reversed | Self::Refunded | Self::Rejected | Self::DeadlineExpired
+        )
+    }
+
+    /// RU: Платеж прошел: средства заблокированы или списаны хотя бы частично.
+    /// EN: The payment went through: funds are at least partly held or charged.
+    pub fn is_successful(&self) -> bool {
+        matches!(
+            self,
+            Self::Authorized | Self::Confirmed | Self::PartialReversed | Self::PartialRefunded
+        )
+    }
+
+    /// RU: Платеж можно подтвердить методом `Confirm`.
+    /// EN: The payment can be confirmed with `Confirm`.
+    pub fn can_confirm(&self) -> bool {
+        matches!(self, Self::Authorized | Self::PartialReversed)
+    }
+
+    /// RU: Авторизацию можно отменить методом `Cancel` (статус REVERSED).
+    /// EN: The authorization can be reversed with `Cancel`.
+    pub fn can_reverse(&self) -> bool {
+        matches!(self, Self::Authorized | Self::PartialReversed)
+    }
+
+    /// RU: По платежу можно сделать возврат методом `Cancel` (статус REFUNDED).
+    /// EN: The payment can be refunded with `Cancel`.
+    pub fn can_refund(&self) -> bool {
+        matches!(self, Self::Confirmed | Self::PartialRefunded)
+    }
+
+    /// RU: Платеж можно отменить методом `Cancel`: отмена авторизации или возврат.
+    /// EN: The payment can be cancelled with `Cancel`, by reversal or refund.
+    pub fn can_cancel(&self) -> bool {
+        self.can_reverse() || self.can_refund()
+    }
+}
+
+impl From<&str> for PaymentStatus {
+    fn from(s: &str) -> Self {
+        match s {
+            "NEW" => Self::New,
+            "FORM_SHOWED" => Self::FormShowed,
+            "AUTHORIZING" => Self::Authorizing,
+            "3DS_CHECKING" => Self::ThreeDsChecking,
+            "3DS_CHECKED" => Self::ThreeDsChecked,
+            "AUTHORIZED" => Self::Authorized,
+            "CONFIRMING" => Self::Confirming,
+            "CONFIRMED" => Self::Confirmed,
+            "REVERSING" => Self::Reversing,
+            "PARTIAL_REVERSED" => Self::PartialReversed,
+            "REVERSED" => Self::Reversed,
+            "REFUNDING" => Self::Refunding,
+            "PARTIAL_REFUNDED" => Self::PartialRefunded,
+            "REFUNDED" => Self::Refunded,
+            "REJECTED" => Self::Rejected,
+            "DEADLINE_EXPIRED" => Self::DeadlineExpired,
+            other => Self::Unknown(other.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for PaymentStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for PaymentStatus {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for PaymentStatus {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Ok(Self::from(s.as_str()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const DOCUMENTED: [&str; 16] = [
+        "NEW",
+        "FORM_SHOWED",
+        "AUTHORIZING",
+        "3DS_CHECKING",
+        "3DS_CHECKED",
+        "AUTHORIZED",
+        "CONFIRMING",
+        "CONFIRMED",
+        "REVERSING",
+        "PARTIAL_REVERSED",
+        "REVERSED",
+        "REFUNDING",
+        "PARTIAL_REFUNDED",
+        "REFUNDED",
+        "REJECTED",
+        "DEADLINE_EXPIRED",
+    ];
+
+    #[test]
+    fn round_trips_documented_statuses() {
+        for s in DOCUMENTED {
+            let status: PaymentStatus = serde_json::from_value(s.into()).unwrap();
+            assert!(!matches!(status, PaymentStatus::Unknown(_)), "{s}");
+            assert_eq!(serde_json::to_value(&status).unwrap(), s);
+        }
+    }
+
+    #[test]
+    fn two_stage_lifecycle() {
+        use PaymentStatus::*;
+
+        assert!(New.can_transition_to(&Authorized));
+        assert!(New.can_transition_to(&Refunded));
+        assert!(Authorized.can_transition_to(&Confirmed));
+        assert!(PartialRefunded.can_transition_to(&PartialRefunded));
+
+        assert!(!Confirmed.can_transition_to(&Authorized));
+        assert!(!Refunded.can_transition_to(&Confirmed));
+        assert!(!Reversed.can_transition_to(&Refunded));
+        assert!(!Authorized.can_transition_to(&Authorized));
+        assert!(!New.can_transition_to(&Unknown("CANCELED".to_string())));
+    }
+
+    #[test]
+    fn final_and_successful() {
+        use PaymentStatus::*;
+
+        for status in [Reversed, Refunded, Rejected, DeadlineExpired] {
+            assert!(status.is_final());
+            assert!(status.next_statuses().is_empty());
+        }
+        assert!(!Confirmed.is_final());
+        assert!(Confirmed.is_successful());
+        assert!(!Rejected.is_successful());
+    }
+
+    #[test]
+    fn allowed_operations() {
+        use PaymentStatus::*;
+
+        assert!(Authorized.can_confirm());
+        assert!(!Confirmed.can_confirm());
+        assert!(Authorized.can_cancel() && Authorized.can_reverse());
+        assert!(Confirmed.can_cancel() && Confirmed.can_refund());
+        assert!(!Rejected.can_cancel());
+    }
+
+    #[test]
+    fn can_confirm_matches_transitions() {
+        for s in DOCUMENTED {
+            let status = PaymentStatus::from(s);
+            let next = status.next_statuses();
+            let direct = next.contains(&PaymentStatus::Confirming)
+                && next.contains(&PaymentStatus::Confirmed);
+
+            assert_eq!(status.can_confirm(), direct, "{s}");
+            if status.can_confirm() {
+                assert!(status.can_transition_to(&PaymentStatus::Confirmed), "{s}");
+            }
+        }
+    }
+
+    #[test]
+    fn keeps_unknown_status() {
+        let status: PaymentStatus = serde_json::from_value("ATTEMPTS_EXPIRED".into()).unwrap();
+
+        assert_eq!(
+            status,
+            PaymentStatus::Unknown("ATTEMPTS_EXPIRED".to_string())
+        );
+        assert_eq!(status.as_str(), "ATTEMPTS_EXPIRED");
+    }
+}
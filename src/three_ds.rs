@@ -0,0 +1,409 @@
+use crate::{
+    Amount, CardId, Client, EncryptedCardData, Error, FinishAuthorizeRes, OrderId, PaymentId,
+    PaymentStatus, RebillId, TerminalKey,
+};
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+/// Версия протокола 3-D Secure, например `1.0.0` или `2.1.0`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ThreeDsVersion(String);
+
+impl ThreeDsVersion {
+    /// RU: Версия из строки. EN: Version from a string.
+    pub fn new(version: impl Into<String>) -> Self {
+        Self(version.into())
+    }
+
+    /// RU: Версия в виде строки. EN: Version as a string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// RU: 3-D Secure второй версии. EN: Whether this is 3-D Secure 2.x.
+    pub fn is_v2(&self) -> bool {
+        self.0.starts_with('2')
+    }
+}
+
+/// Запрос версии 3-D Secure, которую поддерживает карта.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+struct Check3dsVersionReq<'a> {
+    payment_id: &'a PaymentId,
+    card_data: &'a EncryptedCardData,
+}
+
+/// Ответ на запрос версии 3-D Secure.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct Check3dsVersionRes {
+    /// Версия протокола 3-D Secure.
+    pub version: Option<ThreeDsVersion>,
+    /// Идентификатор транзакции на сервере 3DS. Передается только для 3-D Secure v2.
+    #[serde(rename = "TdsServerTransID")]
+    pub tds_server_trans_id: Option<String>,
+    /// Адрес для выполнения метода 3DS Method в браузере покупателя.
+    #[serde(rename = "ThreeDSMethodURL")]
+    pub three_ds_method_url: Option<Url>,
+    /// Платежная система карты.
+    pub payment_system: Option<String>,
+    /// Успешность прохождения запроса.
+    pub success: bool,
+    /// Код ошибки. `0` — если ошибки не произошло.
+    pub error_code: String,
+    /// Краткое описание ошибки.
+    pub message: Option<String>,
+    /// Подробное описание ошибки.
+    pub details: Option<String>,
+}
+
+impl Check3dsVersionRes {
+    /// RU: Форма 3DS Method, если ACS его поддерживает. Результат придет на `notification_url`.
+    /// EN: The 3DS Method form, when the ACS supports it. The result is posted to
+    /// `notification_url`.
+    pub fn three_ds_method(&self, notification_url: &Url) -> Option<ThreeDsMethod> {
+        let url = self.three_ds_method_url.clone()?;
+        let tds_server_trans_id = self.tds_server_trans_id.as_deref()?;
+
+        Some(ThreeDsMethod {
+            url,
+            data: three_ds_method_data(tds_server_trans_id, notification_url),
+        })
+    }
+}
+
+/// RU: Параметр `threeDSMethodData`: JSON с идентификатором транзакции и адресом уведомления в
+/// base64url.
+/// EN: The base64url-encoded `threeDSMethodData` payload.
+pub fn three_ds_method_data(tds_server_trans_id: &str, notification_url: &Url) -> String {
+    let json = serde_json::json!({
+        "threeDSServerTransID": tds_server_trans_id,
+        "threeDSMethodNotificationURL": notification_url.as_str(),
+    });
+
+    URL_SAFE_NO_PAD.encode(json.to_string())
+}
+
+/// Скрытая форма 3DS Method, которая отправляется в невидимом iframe до `FinishAuthorize`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ThreeDsMethod {
+    /// Адрес ACS для 3DS Method.
+    pub url: Url,
+    /// Значение поля `threeDSMethodData`.
+    pub data: String,
+}
+
+impl ThreeDsMethod {
+    /// RU: HTML-форма, которая отправляется автоматически.
+    /// EN: HTML form that submits itself on load.
+    pub fn html_form(&self) -> String {
+        auto_submit_form(&self.url, &[("threeDSMethodData", &self.data)])
+    }
+}
+
+/// Переход покупателя на страницу ACS для прохождения проверки 3-D Secure.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ThreeDsChallenge {
+    /// 3-D Secure v1: поля `PaReq`, `MD` и `TermUrl`.
+    V1 {
+        /// Адрес ACS.
+        acs_url: Url,
+        /// Данные запроса `PaReq`.
+        pa_req: String,
+        /// Идентификатор транзакции `MD`.
+        md: String,
+        /// Адрес, на который ACS вернет покупателя с `PaRes`.
+        term_url: Url,
+    },
+    /// 3-D Secure v2: поле `creq`.
+    V2 {
+        /// Адрес ACS.
+        acs_url: Url,
+        /// Запрос `CReq` в base64url.
+        creq: String,
+    },
+}
+
+impl ThreeDsChallenge {
+    /// RU: Переход на ACS по ответу `FinishAuthorize`. `None` — если проверка 3-D Secure не
+    /// требуется. Для v1 покупатель вернется на `term_url`, без него переход v1 не строится;
+    /// v2 возвращает покупателя на `cresCallbackUrl` из `DATA` запроса `FinishAuthorize` и
+    /// `term_url` не использует.
+    /// EN: Build the ACS redirect from a `FinishAuthorize` response; `None` when no challenge
+    /// is required. `term_url` is only used (and required) for v1.
+    pub fn from_finish_authorize(
+        res: &FinishAuthorizeRes,
+        version: &ThreeDsVersion,
+        term_url: Option<Url>,
+    ) -> Option<Self> {
+        let acs_url = res.acs_url.clone()?;
+
+        if version.is_v2() {
+            let creq = serde_json::json!({
+                "threeDSServerTransID": res.tds_server_trans_id.as_deref()?,
+                "acsTransID": res.acs_trans_id.as_deref()?,
+                "messageVersion": version.as_str(),
+                "challengeWindowSize": "05",
+                "messageType": "CReq",
+            });
+
+            Some(Self::V2 {
+                acs_url,
+                creq: URL_SAFE_NO_PAD.encode(creq.to_string()),
+            })
+        } else {
+            Some(Self::V1 {
+                acs_url,
+                pa_req: res.pa_req.clone()?,
+                md: res.md.clone()?,
+                term_url: term_url?,
+            })
+        }
+    }
+
+    /// RU: HTML-форма, которая отправляется автоматически.
+    /// EN: HTML form that submits itself on load.
+    pub fn html_form(&self) -> String {
+        match self {
+            Self::V1 {
+                acs_url,
+                pa_req,
+                md,
+                term_url,
+            } => auto_submit_form(
+                acs_url,
+                &[
+                    ("PaReq", pa_req),
+                    ("MD", md),
+                    ("TermUrl", term_url.as_str()),
+                ],
+            ),
+            Self::V2 { acs_url, creq } => auto_submit_form(acs_url, &[("creq", creq)]),
+        }
+    }
+}
+
+/// HTML-страница с POST-формой, которая отправляется при загрузке.
+fn auto_submit_form(action: &Url, fields: &[(&str, &str)]) -> String {
+    let inputs: String = fields
+        .iter()
+        .map(|(name, value)| {
+            format!(
+                r#"<input type="hidden" name="{}" value="{}">"#,
+                escape(name),
+                escape(value)
+            )
+        })
+        .collect();
+
+    format!(
+        r#"<html><body onload="document.forms[0].submit()"><form method="POST" action="{}">{inputs}</form></body></html>"#,
+        escape(action.as_str())
+    )
+}
+
+/// Экранирование значения HTML-атрибута.
+fn escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Запрос на завершение платежа после проверки 3-D Secure v1.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct Submit3dsAuthorizationReq {
+    payment_id: PaymentId,
+    #[serde(rename = "MD")]
+    md: String,
+    pa_res: String,
+}
+
+impl Submit3dsAuthorizationReq {
+    /// RU: Результат проверки `PaRes`, который ACS вернул на `TermUrl`.
+    /// EN: The `PaRes` posted back by the ACS to `TermUrl`.
+    pub fn new(payment_id: PaymentId, md: impl Into<String>, pa_res: impl Into<String>) -> Self {
+        Self {
+            payment_id,
+            md: md.into(),
+            pa_res: pa_res.into(),
+        }
+    }
+}
+
+/// Запрос на завершение платежа после проверки 3-D Secure v2.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+struct Submit3dsAuthorizationV2Req<'a> {
+    payment_id: &'a PaymentId,
+}
+
+/// Результат проверки 3-D Secure.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct Submit3dsAuthorizationRes {
+    /// Идентификатор терминала.
+    pub terminal_key: TerminalKey,
+    /// Сумма в копейках.
+    pub amount: Option<Amount>,
+    /// Идентификатор заказа в системе мерчанта.
+    pub order_id: Option<OrderId>,
+    /// Успешность прохождения запроса.
+    pub success: bool,
+    /// Статус платежа.
+    pub status: PaymentStatus,
+    /// Идентификатор платежа в системе Т‑Бизнес.
+    pub payment_id: PaymentId,
+    /// Код ошибки. `0` — если ошибки не произошло.
+    pub error_code: String,
+    /// Краткое описание ошибки.
+    pub message: Option<String>,
+    /// Подробное описание ошибки.
+    pub details: Option<String>,
+    /// Идентификатор автоплатежа.
+    pub rebill_id: Option<RebillId>,
+    /// Идентификатор карты в системе Т‑Бизнес.
+    pub card_id: Option<CardId>,
+}
+
+impl Client {
+    /// RU: Проверить версию 3-D Secure карты (`/v2/Check3dsVersion`).
+    /// EN: Check which 3-D Secure version the card supports (`/v2/Check3dsVersion`).
+    pub async fn check_3ds_version(
+        &self,
+        payment_id: &PaymentId,
+        card_data: &EncryptedCardData,
+    ) -> Result<Check3dsVersionRes, Error> {
+        self.post(
+            "Check3dsVersion",
+            &Check3dsVersionReq {
+                payment_id,
+                card_data,
+            },
+        )
+        .await
+    }
+
+    /// RU: Завершить платеж после 3-D Secure v1 (`/v2/Submit3DSAuthorization`).
+    /// EN: Complete the payment after 3-D Secure v1 (`/v2/Submit3DSAuthorization`).
+    pub async fn submit_3ds_authorization(
+        &self,
+        req: &Submit3dsAuthorizationReq,
+    ) -> Result<Submit3dsAuthorizationRes, Error> {
+        self.post("Submit3DSAuthorization", req).await
+    }
+
+    /// RU: Завершить платеж после 3-D Secure v2 (`/v2/Submit3DSAuthorizationV2`).
+    /// EN: Complete the payment after 3-D Secure v2 (`/v2/Submit3DSAuthorizationV2`).
+    pub async fn submit_3ds_authorization_v2(
+        &self,
+        payment_id: &PaymentId,
+    ) -> Result<Submit3dsAuthorizationRes, Error> {
+        self.post(
+            "Submit3DSAuthorizationV2",
+            &Submit3dsAuthorizationV2Req { payment_id },
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::Value;
+
+    fn finish_authorize(json: &str) -> FinishAuthorizeRes {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn parse_check_3ds_version() {
+        let json = r#"{"Version":"2.1.0","TdsServerTransID":"17fc2fb5-1a4f-4fe4-9bb3-a2e2b8b0f1b6","ThreeDSMethodURL":"https://acs.vendorcert.mirconnect.ru/ds/6300","PaymentSystem":"mir","Success":true,"ErrorCode":"0"}"#;
+        let res: Check3dsVersionRes = serde_json::from_str(json).unwrap();
+
+        assert!(res.version.as_ref().unwrap().is_v2());
+
+        let notification_url = Url::parse("https://shop.example/3ds-method").unwrap();
+        let method = res.three_ds_method(&notification_url).unwrap();
+        let decoded: Value =
+            serde_json::from_slice(&URL_SAFE_NO_PAD.decode(&method.data).unwrap()).unwrap();
+        assert_eq!(
+            decoded["threeDSServerTransID"],
+            "17fc2fb5-1a4f-4fe4-9bb3-a2e2b8b0f1b6"
+        );
+        assert_eq!(
+            decoded["threeDSMethodNotificationURL"],
+            "https://shop.example/3ds-method"
+        );
+        assert!(method.html_form().contains(r#"name="threeDSMethodData""#));
+    }
+
+    #[test]
+    fn v1_challenge_form() {
+        let res = finish_authorize(
+            r#"{"TerminalKey":"TBankTest","Success":true,"Status":"3DS_CHECKING","PaymentId":"13660","ErrorCode":"0","ACSUrl":"https://secure.tcsbank.ru/acs/auth/start.do","MD":"ACQT-563587291","PaReq":"eJxVUt+P"}"#,
+        );
+        let term_url = Url::parse("https://shop.example/3ds?order=1&step=2").unwrap();
+        let version = ThreeDsVersion::new("1.0.0");
+
+        assert!(ThreeDsChallenge::from_finish_authorize(&res, &version, None).is_none());
+        let challenge =
+            ThreeDsChallenge::from_finish_authorize(&res, &version, Some(term_url)).unwrap();
+        let form = challenge.html_form();
+
+        assert!(form.contains(r#"action="https://secure.tcsbank.ru/acs/auth/start.do""#));
+        assert!(form.contains(r#"name="PaReq" value="eJxVUt+P""#));
+        assert!(form.contains(r#"name="MD" value="ACQT-563587291""#));
+        assert!(form.contains(r#"value="https://shop.example/3ds?order=1&amp;step=2""#));
+    }
+
+    #[test]
+    fn v2_challenge_creq() {
+        let res = finish_authorize(
+            r#"{"TerminalKey":"TBankTest","Success":true,"Status":"3DS_CHECKING","PaymentId":"13660","ErrorCode":"0","ACSUrl":"https://acs.example/challenge","TdsServerTransId":"tds-1","AcsTransId":"acs-1"}"#,
+        );
+        let Some(ThreeDsChallenge::V2 { creq, .. }) =
+            ThreeDsChallenge::from_finish_authorize(&res, &ThreeDsVersion::new("2.1.0"), None)
+        else {
+            panic!("expected a 3DS v2 challenge");
+        };
+        let decoded: Value =
+            serde_json::from_slice(&URL_SAFE_NO_PAD.decode(creq).unwrap()).unwrap();
+
+        assert_eq!(decoded["threeDSServerTransID"], "tds-1");
+        assert_eq!(decoded["acsTransID"], "acs-1");
+        assert_eq!(decoded["messageVersion"], "2.1.0");
+        assert_eq!(decoded["messageType"], "CReq");
+    }
+
+    #[test]
+    fn no_challenge_without_acs_url() {
+        let res = finish_authorize(
+            r#"{"TerminalKey":"TBankTest","Success":true,"Status":"CONFIRMED","PaymentId":"13660","ErrorCode":"0"}"#,
+        );
+        assert!(
+            ThreeDsChallenge::from_finish_authorize(&res, &ThreeDsVersion::new("2.1.0"), None)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn parse_submit_response() {
+        let json = r#"{"TerminalKey":"TBankTest","OrderId":"21050","Success":true,"Status":"CONFIRMED","PaymentId":"13660","ErrorCode":"0","Amount":100000}"#;
+        let res: Submit3dsAuthorizationRes = serde_json::from_str(json).unwrap();
+
+        assert_eq!(res.status, PaymentStatus::Confirmed);
+    }
+}
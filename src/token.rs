@@ -0,0 +1,168 @@
+use crate::{Error, Password, TerminalKey};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use sha2::{Digest, Sha256};
+
+/// Requirements: 64 characters, hex
+///
+/// Подпись запроса.
+///
+/// Считается по параметрам корневого уровня: вложенные объекты и массивы (`DATA`, `Receipt`, `Shops`)
+/// не участвуют. К параметрам добавляется пароль терминала `Password`, пары сортируются по ключу,
+/// значения склеиваются в одну строку и хэшируются SHA-256.
+///
+/// [Подробнее](https://developer.tbank.ru/eacq/intro/developer/token)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(transparent)]
+pub struct Token(String);
+
+impl Token {
+    /// RU: Посчитать токен для параметров корневого уровня запроса или уведомления.
+    /// EN: Compute the token over root-level request or notification parameters.
+    ///
+    /// `Token` itself, nested objects, arrays and `null` values are skipped.
+    pub fn generate(params: &Map<String, Value>, password: &Password) -> Self {
+        let mut pairs: Vec<(&str, String)> = params
+            .iter()
+            .filter(|(key, _)| key.as_str() != "Token")
+            .filter_map(|(key, value)| scalar(value).map(|v| (key.as_str(), v)))
+            .collect();
+        pairs.push(("Password", password.0.clone()));
+        pairs.sort_unstable_by(|a, b| a.0.cmp(b.0));
+
+        let concatenated: String = pairs.into_iter().map(|(_, v)| v).collect();
+        let digest = Sha256::digest(concatenated.as_bytes());
+
+        Self(format!("{digest:x}"))
+    }
+
+    /// RU: Значение токена.
+    /// EN: Token value as a hex string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Строковое представление скалярного параметра для подписи. `None` — параметр не подписывается.
+fn scalar(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        Value::Null | Value::Array(_) | Value::Object(_) => None,
+    }
+}
+
+/// RU: Тело запроса, которое подписывается перед отправкой.
+/// EN: A request body that gets signed before it is sent.
+///
+/// Реализован для любого `Serialize`; [`Client`](crate::Client) вызывает его автоматически.
+pub trait Signable: Serialize {
+    /// RU: Добавить `TerminalKey` и `Token` к параметрам запроса.
+    /// EN: Add `TerminalKey` and `Token` to the serialized request.
+    ///
+    /// Root-level `null` values are dropped from the body so they are neither sent nor signed.
+    fn sign(&self, terminal_key: &TerminalKey, password: &Password) -> Result<Value, Error> {
+        let value = serde_json::to_value(self).map_err(|e| Error::Config(e.to_string()))?;
+        let Value::Object(mut params) = value else {
+            return Err(Error::Config(
+                "request body must serialize to a JSON object".to_string(),
+            ));
+        };
+
+        params.retain(|_, v| !v.is_null());
+        let _ = params.insert(
+            "TerminalKey".to_string(),
+            Value::String(terminal_key.as_str().to_string()),
+        );
+
+        let token = Token::generate(&params, password);
+        let _ = params.insert("Token".to_string(), Value::String(token.0));
+
+        Ok(Value::Object(params))
+    }
+}
+
+impl<T: Serialize + ?Sized> Signable for T {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn object(json: &str) -> Map<String, Value> {
+        match serde_json::from_str(json).unwrap() {
+            Value::Object(map) => map,
+            _ => panic!("not an object"),
+        }
+    }
+
+    #[test]
+    fn documented_example() {
+        let params = object(
+            r#"{"TerminalKey":"MerchantTerminalKey","Amount":19200,"OrderId":"21090","Description":"Подарочная карта на 1000 рублей","DATA":{"Phone":"+71234567890","Email":"a@test.com"},"Receipt":{"Email":"a@test.ru","Phone":"+79031234567","Taxation":"osn","Items":[{"Name":"Наименование товара 1","Price":10000,"Quantity":1,"Amount":10000,"Tax":"vat10","Ean13":"303130323930303030630333435"}]}}"#,
+        );
+        let password = Password::new("usaf8fw8fsw21g");
+
+        assert_eq!(
+            Token::generate(&params, &password).as_str(),
+            "0024a00af7c350a3a67ca168ce06502aa72772456662e38696d48b56ee9c97d9"
+        );
+    }
+
+    #[test]
+    fn ignores_existing_token_and_nulls() {
+        let password = Password::new("usaf8fw8fsw21g");
+        let plain = object(r#"{"Amount":19200,"OrderId":"21090"}"#);
+        let noisy =
+            object(r#"{"Amount":19200,"OrderId":"21090","Token":"abc","Description":null}"#);
+
+        assert_eq!(
+            Token::generate(&plain, &password),
+            Token::generate(&noisy, &password)
+        );
+    }
+
+    #[test]
+    fn booleans_are_lowercase() {
+        let password = Password::new("p");
+        let params = object(r#"{"Success":true,"OrderId":"1"}"#);
+        let digest = Sha256::digest("1ptrue".as_bytes());
+
+        assert_eq!(
+            Token::generate(&params, &password).as_str(),
+            format!("{digest:x}")
+        );
+    }
+
+    #[test]
+    fn sign_adds_terminal_key_and_token() {
+        #[derive(Serialize)]
+        #[serde(rename_all = "PascalCase")]
+        struct Req {
+            amount: u32,
+            order_id: &'static str,
+            description: Option<&'static str>,
+        }
+
+        let req = Req {
+            amount: 19200,
+            order_id: "21090",
+            description: None,
+        };
+        let signed = req
+            .sign(
+                &TerminalKey::new("MerchantTerminalKey").unwrap(),
+                &Password::new("usaf8fw8fsw21g"),
+            )
+            .unwrap();
+
+        assert_eq!(signed["TerminalKey"], "MerchantTerminalKey");
+        assert!(signed.get("Description").is_none());
+
+        let mut expected =
+            object(r#"{"Amount":19200,"OrderId":"21090","TerminalKey":"MerchantTerminalKey"}"#);
+        let token = Token::generate(&expected, &Password::new("usaf8fw8fsw21g"));
+        let _ = expected.insert("Token".to_string(), Value::String(token.0));
+        assert_eq!(signed, Value::Object(expected));
+    }
+}
@@ -0,0 +1,251 @@
+use crate::{
+    ApiVersion, Client, Device, Error, PaymentId, Service, TerminalKey, client::svg_response,
+};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+/// Версия T‑Pay, например `1.0` или `2.0`.
+///
+/// Версия 1.0 поддерживает только оплату с мобильного устройства по ссылке, начиная с 2.0
+/// доступна оплата с десктопа по QR-коду.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct TPayVersion(String);
+
+impl TPayVersion {
+    /// RU: Версия из строки. EN: Version from a string.
+    pub fn new(version: impl Into<String>) -> Self {
+        Self(version.into())
+    }
+
+    /// RU: Версия в виде строки. EN: Version as a string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// RU: Версия поддерживает оплату по QR-коду. EN: Whether QR payments are supported.
+    pub fn supports_qr(&self) -> bool {
+        self.0
+            .split('.')
+            .next()
+            .and_then(|major| major.parse::<u32>().ok())
+            .is_some_and(|major| major >= 2)
+    }
+}
+
+/// Ответ на проверку доступности T‑Pay на терминале.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct TPayStatusRes {
+    /// Успешность прохождения запроса.
+    pub success: bool,
+    /// Код ошибки. `0` — если ошибки не произошло.
+    pub error_code: String,
+    /// Краткое описание ошибки.
+    pub message: Option<String>,
+    /// Подробное описание ошибки.
+    pub details: Option<String>,
+    /// Параметры T‑Pay на терминале.
+    pub params: Option<TPayStatusParams>,
+}
+
+/// Параметры T‑Pay на терминале.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct TPayStatusParams {
+    /// T‑Pay подключен на терминале.
+    pub allowed: bool,
+    /// Версия T‑Pay.
+    pub version: Option<TPayVersion>,
+}
+
+/// Как показать кнопку T‑Pay покупателю.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TPayButton {
+    /// T‑Pay недоступен — кнопку не показывать.
+    Hidden,
+    /// Показать кнопку, которая ведет по ссылке в приложение Т‑Банка.
+    Link(TPayVersion),
+    /// Показать QR-код для сканирования с телефона.
+    Qr(TPayVersion),
+}
+
+impl TPayStatusRes {
+    /// RU: Показывать ли кнопку T‑Pay на устройстве `device` и в каком виде.
+    /// EN: Whether and how to show the T-Pay button on `device`.
+    pub fn button_for(&self, device: Device) -> TPayButton {
+        let Some(TPayStatusParams {
+            allowed: true,
+            version: Some(version),
+        }) = self.params.as_ref().filter(|_| self.success)
+        else {
+            return TPayButton::Hidden;
+        };
+
+        match device {
+            Device::Sdk | Device::Mobile => TPayButton::Link(version.clone()),
+            Device::Desktop if version.supports_qr() => TPayButton::Qr(version.clone()),
+            Device::Desktop => TPayButton::Hidden,
+        }
+    }
+}
+
+/// Ответ на запрос ссылки T‑Pay.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct TPayLinkRes {
+    /// Успешность прохождения запроса.
+    pub success: bool,
+    /// Код ошибки. `0` — если ошибки не произошло.
+    pub error_code: String,
+    /// Краткое описание ошибки.
+    pub message: Option<String>,
+    /// Подробное описание ошибки.
+    pub details: Option<String>,
+    /// Параметры ссылки.
+    pub params: Option<TPayLinkParams>,
+}
+
+/// Ссылка на оплату в T‑Pay.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct TPayLinkParams {
+    /// Ссылка для перехода в приложение Т‑Банка.
+    pub redirect_url: Url,
+    /// Ссылка для оплаты по QR-коду с десктопа.
+    #[serde(rename = "WebQR")]
+    pub web_qr: Option<Url>,
+}
+
+impl Client {
+    /// RU: Проверить, доступен ли T‑Pay на терминале (`/v2/TinkoffPay/terminals/{TerminalKey}/status`).
+    /// EN: Check whether T-Pay is enabled on the terminal.
+    pub async fn tpay_status(&self) -> Result<TPayStatusRes, Error> {
+        self.get(
+            Service::TinkoffPay,
+            &tpay_status_path(self.terminal_key()),
+            &(),
+        )
+        .await
+    }
+
+    /// RU: Получить ссылку на оплату в T‑Pay
+    /// (`/v2/TinkoffPay/transactions/{PaymentId}/versions/{Version}/link`).
+    /// EN: Get the T-Pay payment link for the given version.
+    pub async fn tpay_link(
+        &self,
+        payment_id: &PaymentId,
+        version: &TPayVersion,
+    ) -> Result<TPayLinkRes, Error> {
+        self.get(
+            Service::TinkoffPay,
+            &tpay_transaction_path(payment_id, version, "link"),
+            &(),
+        )
+        .await
+    }
+
+    /// RU: Получить SVG-изображение QR-кода T‑Pay
+    /// (`/v2/TinkoffPay/transactions/{PaymentId}/versions/{Version}/qr`).
+    /// EN: Get the T-Pay QR code as an SVG image.
+    pub async fn tpay_qr(
+        &self,
+        payment_id: &PaymentId,
+        version: &TPayVersion,
+    ) -> Result<String, Error> {
+        let url = self.url(
+            Service::TinkoffPay,
+            ApiVersion::V2,
+            &tpay_transaction_path(payment_id, version, "qr"),
+        );
+
+        svg_response(self.send_raw(self.client.get(url)).await?)
+    }
+}
+
+fn tpay_status_path(terminal_key: &TerminalKey) -> String {
+    format!("terminals/{}/status", terminal_key.as_str())
+}
+
+fn tpay_transaction_path(payment_id: &PaymentId, version: &TPayVersion, kind: &str) -> String {
+    format!(
+        "transactions/{}/versions/{}/{kind}",
+        payment_id.as_str(),
+        version.as_str()
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn status(json: &str) -> TPayStatusRes {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn button_depends_on_device_and_version() {
+        let v2 =
+            status(r#"{"Success":true,"ErrorCode":"0","Params":{"Allowed":true,"Version":"2.0"}}"#);
+        let v1 =
+            status(r#"{"Success":true,"ErrorCode":"0","Params":{"Allowed":true,"Version":"1.0"}}"#);
+        let off = status(r#"{"Success":true,"ErrorCode":"0","Params":{"Allowed":false}}"#);
+
+        assert_eq!(
+            v2.button_for(Device::Desktop),
+            TPayButton::Qr(TPayVersion::new("2.0"))
+        );
+        assert_eq!(
+            v2.button_for(Device::Mobile),
+            TPayButton::Link(TPayVersion::new("2.0"))
+        );
+        assert_eq!(
+            v1.button_for(Device::Sdk),
+            TPayButton::Link(TPayVersion::new("1.0"))
+        );
+        assert_eq!(v1.button_for(Device::Desktop), TPayButton::Hidden);
+        assert_eq!(off.button_for(Device::Mobile), TPayButton::Hidden);
+    }
+
+    #[test]
+    fn parse_link() {
+        let json = r#"{"Success":true,"ErrorCode":"0","Params":{"RedirectUrl":"https://www.tinkoff.ru/tpay/1923863684","WebQR":"https://www.tinkoff.ru/tpay/1923863684?qr=true"}}"#;
+        let res: TPayLinkRes = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            res.params.unwrap().redirect_url.as_str(),
+            "https://www.tinkoff.ru/tpay/1923863684"
+        );
+    }
+
+    #[test]
+    fn paths() {
+        assert_eq!(
+            tpay_status_path(&TerminalKey::new("TBankTest").unwrap()),
+            "terminals/TBankTest/status"
+        );
+        assert_eq!(
+            tpay_transaction_path(&PaymentId::new("700"), &TPayVersion::new("2.0"), "qr"),
+            "transactions/700/versions/2.0/qr"
+        );
+    }
+
+    #[test]
+    fn qr_rejects_error_body() {
+        let json = r#"{"Success":false,"ErrorCode":"3001","Message":"T-Pay недоступен","Details":"Терминал не поддерживает T-Pay"}"#;
+
+        match svg_response(json.to_string()) {
+            Err(Error::Api { code, details, .. }) => {
+                assert_eq!(code, "3001");
+                assert_eq!(details.as_deref(), Some("Терминал не поддерживает T-Pay"));
+            }
+            other => panic!("expected Error::Api, got {other:?}"),
+        }
+        assert!(matches!(
+            svg_response("<html>Bad Gateway</html>".to_string()),
+            Err(Error::Deserialize { .. })
+        ));
+
+        let svg = r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 1 1"></svg>"#;
+        assert_eq!(svg_response(svg.to_string()).unwrap(), svg);
+    }
+}
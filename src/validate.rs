@@ -0,0 +1,79 @@
+use crate::Error;
+use validator::{ValidateEmail, ValidateLength};
+
+/// Проверка длины строкового параметра в символах.
+pub(crate) fn length(field: &'static str, value: &str, min: u64, max: u64) -> Result<(), Error> {
+    if !value.validate_length(Some(min), Some(max), None) {
+        return Err(Error::Validation {
+            field,
+            reason: format!(
+                "expected {min} to {max} characters, got {}",
+                value.chars().count()
+            ),
+        });
+    }
+    Ok(())
+}
+
+/// Проверка адреса электронной почты.
+pub(crate) fn email(field: &'static str, value: &str) -> Result<(), Error> {
+    if !value.validate_email() {
+        return Err(Error::Validation {
+            field,
+            reason: format!("{value:?} is not a valid email address"),
+        });
+    }
+    Ok(())
+}
+
+/// Проверка строки из цифр заданной длины.
+pub(crate) fn digits(field: &'static str, value: &str, lengths: &[usize]) -> Result<(), Error> {
+    if !lengths.contains(&value.len()) || !value.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(Error::Validation {
+            field,
+            reason: format!("expected {lengths:?} digits, got {value:?}"),
+        });
+    }
+    Ok(())
+}
+
+/// Реализует `TryFrom<&str>` и проверяющий `Deserialize` для строкового newtype
+/// с функцией `fn check(&str) -> Result<(), Error>`.
+macro_rules! validated_string {
+    ($ty:ident) => {
+        impl TryFrom<&str> for $ty {
+            type Error = $crate::Error;
+
+            fn try_from(value: &str) -> Result<Self, Self::Error> {
+                Self::check(value)?;
+                Ok(Self(value.to_string()))
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $ty {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let value = String::deserialize(deserializer)?;
+                Self::check(&value).map_err(serde::de::Error::custom)?;
+                Ok(Self(value))
+            }
+        }
+    };
+}
+
+pub(crate) use validated_string;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn checks() {
+        assert!(length("OrderId", "ё".repeat(36).as_str(), 1, 36).is_ok());
+        assert!(length("OrderId", "", 1, 36).is_err());
+        assert!(email("Email", "a@test.ru").is_ok());
+        assert!(email("Email", "a@").is_err());
+        assert!(digits("Inn", "7707083893", &[10, 12]).is_ok());
+        assert!(digits("Inn", "77070838930", &[10, 12]).is_err());
+        assert!(digits("Inn", "77070838a3", &[10, 12]).is_err());
+    }
+}
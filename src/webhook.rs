@@ -0,0 +1,281 @@
+use crate::{Notification, Password, verify_notification};
+use std::fmt;
+use std::future::Future;
+use std::sync::Arc;
+use tracing::debug;
+
+/// RU: Обработчик уведомлений, не привязанный к веб-фреймворку.
+/// EN: Framework-agnostic notification endpoint.
+///
+/// Проверяет подпись, передает [Notification] в пользовательский обработчик и формирует ответ,
+/// который ожидает Т‑Бизнес: HTTP 200 с телом `OK`. Любой другой ответ заставит Т‑Бизнес
+/// повторить отправку уведомления.
+///
+/// С фичами `axum` и `hyper` обработчик подключается к роутеру без дополнительного кода.
+pub struct WebhookHandler<F> {
+    inner: Arc<Inner<F>>,
+}
+
+struct Inner<F> {
+    password: Password,
+    callback: F,
+}
+
+impl<F> Clone for WebhookHandler<F> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: Arc::clone(&self.inner),
+        }
+    }
+}
+
+impl<F> fmt::Debug for WebhookHandler<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WebhookHandler")
+            .field("password", &self.inner.password)
+            .finish_non_exhaustive()
+    }
+}
+
+/// RU: Ответ на уведомление. EN: Response to send back to T-Bank.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WebhookResponse {
+    /// RU: HTTP-статус. EN: HTTP status code.
+    pub status: u16,
+    /// RU: Тело ответа. EN: Response body.
+    pub body: &'static str,
+}
+
+impl WebhookResponse {
+    /// Уведомление принято — Т‑Бизнес не будет отправлять его повторно.
+    pub const OK: Self = Self {
+        status: 200,
+        body: "OK",
+    };
+
+    /// Подпись не прошла проверку или тело не разобрано.
+    pub const BAD_REQUEST: Self = Self {
+        status: 400,
+        body: "INVALID NOTIFICATION",
+    };
+
+    /// Пользовательский обработчик вернул ошибку — Т‑Бизнес повторит уведомление.
+    pub const ERROR: Self = Self {
+        status: 500,
+        body: "ERROR",
+    };
+
+    /// RU: Уведомление принято. EN: Whether T-Bank will treat the notification as delivered.
+    pub fn is_ok(&self) -> bool {
+        *self == Self::OK
+    }
+}
+
+impl<F, Fut, E> WebhookHandler<F>
+where
+    F: Fn(Notification) -> Fut,
+    Fut: Future<Output = Result<(), E>>,
+    E: fmt::Display,
+{
+    /// RU: Создать обработчик с паролем терминала и пользовательским колбэком.
+    /// EN: Create a handler from the terminal password and a user callback.
+    pub fn new(password: Password, callback: F) -> Self {
+        Self {
+            inner: Arc::new(Inner { password, callback }),
+        }
+    }
+
+    /// RU: Обработать сырое тело уведомления.
+    /// EN: Handle the raw notification body.
+    pub async fn handle(&self, body: &[u8]) -> WebhookResponse {
+        let notification = match verify_notification(body, &self.inner.password) {
+            Ok(notification) => notification,
+            Err(e) => {
+                debug!("Rejecting notification: {e}");
+                return WebhookResponse::BAD_REQUEST;
+            }
+        };
+
+        match (self.inner.callback)(notification).await {
+            Ok(()) => WebhookResponse::OK,
+            Err(e) => {
+                debug!("Notification callback failed: {e}");
+                WebhookResponse::ERROR
+            }
+        }
+    }
+}
+
+#[cfg(feature = "axum")]
+mod axum_adapter {
+    use super::*;
+    use axum::Router;
+    use axum::body::Bytes;
+    use axum::http::StatusCode;
+    use axum::response::{IntoResponse, Response};
+    use axum::routing::post;
+
+    impl IntoResponse for WebhookResponse {
+        fn into_response(self) -> Response {
+            let status =
+                StatusCode::from_u16(self.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+            (status, self.body).into_response()
+        }
+    }
+
+    impl<F, Fut, E> WebhookHandler<F>
+    where
+        F: Fn(Notification) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), E>> + Send + 'static,
+        E: fmt::Display + 'static,
+    {
+        /// RU: Роутер axum с POST-обработчиком по указанному пути.
+        /// EN: An axum router serving POST notifications at `path`.
+        pub fn into_router<S>(self, path: &str) -> Router<S>
+        where
+            S: Clone + Send + Sync + 'static,
+        {
+            Router::new().route(
+                path,
+                post(move |body: Bytes| async move { self.handle(&body).await }),
+            )
+        }
+    }
+}
+
+#[cfg(feature = "hyper")]
+mod hyper_adapter {
+    use super::*;
+    use http_body_util::{BodyExt, Full};
+    use hyper::body::{Body, Bytes};
+    use hyper::{Request, Response, StatusCode};
+
+    impl From<WebhookResponse> for Response<Full<Bytes>> {
+        fn from(res: WebhookResponse) -> Self {
+            let mut response = Response::new(Full::new(Bytes::from_static(res.body.as_bytes())));
+            *response.status_mut() =
+                StatusCode::from_u16(res.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+            response
+        }
+    }
+
+    impl<F, Fut, E> WebhookHandler<F>
+    where
+        F: Fn(Notification) -> Fut,
+        Fut: Future<Output = Result<(), E>>,
+        E: fmt::Display,
+    {
+        /// RU: Обработать запрос hyper — подходит для `hyper::service::service_fn`.
+        /// EN: Handle a hyper request; suitable for `hyper::service::service_fn`.
+        pub async fn handle_hyper<B>(&self, req: Request<B>) -> Response<Full<Bytes>>
+        where
+            B: Body,
+            B::Error: fmt::Display,
+        {
+            let body = match req.into_body().collect().await {
+                Ok(collected) => collected.to_bytes(),
+                Err(e) => {
+                    debug!("Failed to read notification body: {e}");
+                    return WebhookResponse::BAD_REQUEST.into();
+                }
+            };
+
+            self.handle(&body).await.into()
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::notification::test::{PASSWORD, signed};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn payment() -> Vec<u8> {
+        signed(
+            r#"{"TerminalKey":"1321054611234DEMO","OrderId":"201709","Success":true,"Status":"CONFIRMED","PaymentId":8742591,"ErrorCode":"0","Amount":9855}"#,
+        )
+    }
+
+    #[tokio::test]
+    async fn acknowledges_valid_notification() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&calls);
+        let handler = WebhookHandler::new(Password::new(PASSWORD), move |n| {
+            let counter = Arc::clone(&counter);
+            async move {
+                assert!(matches!(n, Notification::Payment(_)));
+                let _ = counter.fetch_add(1, Ordering::SeqCst);
+                Ok::<_, String>(())
+            }
+        });
+
+        let res = handler.handle(&payment()).await;
+
+        assert!(res.is_ok());
+        assert_eq!(res.body, "OK");
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn rejects_bad_signature_without_calling_back() {
+        let handler =
+            WebhookHandler::new(Password::new("wrong"), |_| async { Err("callback ran") });
+
+        assert_eq!(
+            handler.handle(&payment()).await,
+            WebhookResponse::BAD_REQUEST
+        );
+    }
+
+    #[tokio::test]
+    async fn callback_error_asks_for_retry() {
+        let handler = WebhookHandler::new(Password::new(PASSWORD), |_| async {
+            Err("database is down")
+        });
+
+        assert_eq!(handler.handle(&payment()).await, WebhookResponse::ERROR);
+    }
+
+    #[cfg(feature = "axum")]
+    #[tokio::test]
+    async fn axum_router() {
+        use axum::body::Body;
+        use axum::http::Request;
+        use http_body_util::BodyExt;
+        use tower::ServiceExt;
+
+        let handler =
+            WebhookHandler::new(Password::new(PASSWORD), |_| async { Ok::<_, String>(()) });
+        let router: axum::Router = handler.into_router("/tbank");
+
+        let res = router
+            .oneshot(Request::post("/tbank").body(Body::from(payment())).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(res.status(), 200);
+        let body = res.into_body().collect().await.unwrap().to_bytes();
+        assert_eq!(&body[..], b"OK");
+    }
+
+    #[cfg(feature = "hyper")]
+    #[tokio::test]
+    async fn hyper_request() {
+        use http_body_util::{BodyExt, Full};
+        use hyper::Request;
+        use hyper::body::Bytes;
+
+        let handler =
+            WebhookHandler::new(Password::new(PASSWORD), |_| async { Ok::<_, String>(()) });
+        let req = Request::post("/tbank")
+            .body(Full::new(Bytes::from(payment())))
+            .unwrap();
+
+        let res = handler.handle_hyper(req).await;
+
+        assert_eq!(res.status(), 200);
+        let body = res.into_body().collect().await.unwrap().to_bytes();
+        assert_eq!(&body[..], b"OK");
+    }
+}